@@ -76,6 +76,7 @@ fn setup(mut commands: Commands, mut materials: ResMut<Assets<ColorMaterial>>) {
                     bevy_easings::EasingType::PingPong {
                         duration: std::time::Duration::from_secs(1),
                         pause: Some(std::time::Duration::from_millis(500)),
+                        times: None,
                     },
                 ),
             );
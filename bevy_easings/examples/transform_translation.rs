@@ -70,6 +70,7 @@ fn setup(mut commands: Commands, mut materials: ResMut<Assets<ColorMaterial>>) {
                     bevy_easings::EasingType::PingPong {
                         duration: std::time::Duration::from_secs(1),
                         pause: Some(std::time::Duration::from_millis(500)),
+                        times: None,
                     },
                 ),
             );
@@ -92,6 +93,7 @@ fn setup(mut commands: Commands, mut materials: ResMut<Assets<ColorMaterial>>) {
                 bevy_easings::EasingType::PingPong {
                     duration: std::time::Duration::from_secs(1),
                     pause: Some(std::time::Duration::from_millis(500)),
+                    times: None,
                 },
             ),
         );
@@ -113,6 +115,7 @@ fn setup(mut commands: Commands, mut materials: ResMut<Assets<ColorMaterial>>) {
                 bevy_easings::EasingType::PingPong {
                     duration: std::time::Duration::from_secs(1),
                     pause: Some(std::time::Duration::from_millis(500)),
+                    times: None,
                 },
             ),
         );
@@ -134,6 +137,7 @@ fn setup(mut commands: Commands, mut materials: ResMut<Assets<ColorMaterial>>) {
                 bevy_easings::EasingType::PingPong {
                     duration: std::time::Duration::from_secs(1),
                     pause: Some(std::time::Duration::from_millis(500)),
+                    times: None,
                 },
             ),
         );
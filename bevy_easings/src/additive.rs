@@ -0,0 +1,129 @@
+use bevy::prelude::*;
+
+use crate::{EaseMethod, EasingComponent, EasingType};
+
+/// Stage in which additive easings are applied, after `CoreStage::Update`. Their contribution is
+/// removed again in `CoreStage::PreUpdate`, so that other systems work on the base value
+pub const ADDITIVE_EASING_STAGE: &str = "easings_additive";
+
+/// Trait for values that can be eased relatively, by adding a delta on top of their current value
+pub trait Additive: Sized {
+    /// Value that doesn't change anything when added
+    fn zero() -> Self;
+
+    /// Add `delta` to this value
+    fn add(&self, delta: &Self) -> Self;
+
+    /// Remove `delta` from this value
+    fn remove(&self, delta: &Self) -> Self;
+
+    /// Create a new easing adding `self` to the current value of the component
+    fn ease_by(
+        self,
+        ease_function: impl Into<EaseMethod>,
+        easing_type: EasingType,
+    ) -> AdditiveEasing<Self> {
+        AdditiveEasing {
+            easing: EasingComponent::new(Some(Self::zero()), self, ease_function, easing_type),
+            applied: None,
+        }
+    }
+}
+
+/// Component to control an additive easing. It can be on the same entity as an easing of the same
+/// component, or as systems writing to it
+pub struct AdditiveEasing<T> {
+    /// Easing of the delta, from `Additive::zero` to the delta
+    pub easing: EasingComponent<T>,
+    pub(crate) applied: Option<T>,
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for AdditiveEasing<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AdditiveEasing")
+            .field("easing", &self.easing)
+            .field("applied", &self.applied)
+            .finish()
+    }
+}
+
+impl Additive for Transform {
+    fn zero() -> Self {
+        Transform::identity()
+    }
+
+    fn add(&self, delta: &Self) -> Self {
+        Transform {
+            translation: self.translation + delta.translation,
+            rotation: delta.rotation * self.rotation,
+            scale: self.scale * delta.scale,
+        }
+    }
+
+    fn remove(&self, delta: &Self) -> Self {
+        Transform {
+            translation: self.translation - delta.translation,
+            rotation: delta.rotation.conjugate() * self.rotation,
+            scale: self.scale / delta.scale,
+        }
+    }
+}
+
+impl Additive for Sprite {
+    fn zero() -> Self {
+        Sprite {
+            size: Vec2::ZERO,
+            ..Default::default()
+        }
+    }
+
+    fn add(&self, delta: &Self) -> Self {
+        Sprite {
+            size: self.size + delta.size,
+            ..self.clone()
+        }
+    }
+
+    fn remove(&self, delta: &Self) -> Self {
+        Sprite {
+            size: self.size - delta.size,
+            ..self.clone()
+        }
+    }
+}
+
+impl Additive for Color {
+    fn zero() -> Self {
+        Color::rgba(0., 0., 0., 0.)
+    }
+
+    fn add(&self, delta: &Self) -> Self {
+        let [r, g, b, a] = self.as_rgba_f32();
+        let [dr, dg, db, da] = delta.as_rgba_f32();
+        Color::rgba(r + dr, g + dg, b + db, a + da)
+    }
+
+    fn remove(&self, delta: &Self) -> Self {
+        let [r, g, b, a] = self.as_rgba_f32();
+        let [dr, dg, db, da] = delta.as_rgba_f32();
+        Color::rgba(r - dr, g - dg, b - db, a - da)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color_round_trip() {
+        let base = Color::rgba(0.2, 0.4, 0.6, 0.8);
+        let delta = Color::rgba(0.1, -0.2, 0.3, 0.1);
+        let mut color = base;
+        for _ in 0..10 {
+            color = color.add(&delta).remove(&delta);
+        }
+        for (actual, expected) in color.as_rgba_f32().iter().zip(base.as_rgba_f32().iter()) {
+            assert!((actual - expected).abs() < 1e-5);
+        }
+    }
+}
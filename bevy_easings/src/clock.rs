@@ -0,0 +1,96 @@
+use std::{any::TypeId, collections::HashMap, time::Duration};
+
+use bevy::prelude::*;
+
+/// Clock used to advance an easing
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum EasingClock {
+    /// Real time, from `Time`
+    Real,
+    /// Game time, real time scaled and paused by the `GameClock` resource
+    Game,
+    /// A resource implementing `EasingTimeSource`. Create it with `EasingClock::custom`
+    Custom(TypeId),
+}
+
+impl EasingClock {
+    /// Clock reading its time from the resource `R`. The system `custom_clock_system::<R>` needs
+    /// to be added to the application, before the label `"easing_clocks"`
+    pub fn custom<R: EasingTimeSource>() -> Self {
+        EasingClock::Custom(TypeId::of::<R>())
+    }
+}
+
+/// Resource controlling the game clock, used by easings on `EasingClock::Game`
+#[derive(Debug, Clone, Copy)]
+pub struct GameClock {
+    /// Scale applied to real time
+    pub scale: f32,
+    /// Stop the game clock
+    pub paused: bool,
+}
+
+impl Default for GameClock {
+    fn default() -> Self {
+        GameClock {
+            scale: 1.,
+            paused: false,
+        }
+    }
+}
+
+/// Trait for resources that can be used as a clock for easings
+pub trait EasingTimeSource: Send + Sync + 'static {
+    /// Time elapsed since last frame for this clock
+    fn delta(&self, time: &Time) -> Duration;
+}
+
+/// Resource holding the time elapsed since last frame for each clock, and the position of each
+/// `Timeline`. Insert it before adding the plugin to change the clock used by easings that don't
+/// select one
+#[derive(Debug)]
+pub struct EasingClocks {
+    default: EasingClock,
+    deltas: HashMap<EasingClock, Duration>,
+    timelines: HashMap<Entity, Duration>,
+}
+
+impl Default for EasingClocks {
+    fn default() -> Self {
+        EasingClocks::with_default(EasingClock::Real)
+    }
+}
+
+impl EasingClocks {
+    /// Use `clock` for easings that don't select one
+    pub fn with_default(clock: EasingClock) -> Self {
+        EasingClocks {
+            default: clock,
+            deltas: HashMap::default(),
+            timelines: HashMap::default(),
+        }
+    }
+
+    pub(crate) fn set_delta(&mut self, clock: EasingClock, delta: Duration) {
+        self.deltas.insert(clock, delta);
+    }
+
+    pub(crate) fn delta(&self, clock: Option<EasingClock>) -> Duration {
+        self.deltas
+            .get(&clock.unwrap_or(self.default))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn clear_timelines(&mut self) {
+        self.timelines.clear();
+    }
+
+    pub(crate) fn set_timeline(&mut self, timeline: Entity, elapsed: Duration) {
+        self.timelines.insert(timeline, elapsed);
+    }
+
+    pub(crate) fn timeline(&self, timeline: Entity) -> Option<Duration> {
+        self.timelines.get(&timeline).copied()
+    }
+}
@@ -0,0 +1,276 @@
+use bevy::prelude::*;
+
+/// Color space in which colors are interpolated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorSpace {
+    /// Interpolate the sRGB components
+    Srgb,
+    /// Interpolate the linear RGB components, physically correct when mixing light
+    LinearRgb,
+    /// Interpolate hue, saturation and lightness, taking the shortest way around the hue circle
+    Hsl,
+    /// Interpolate in the perceptual Oklab space, avoiding muddy midpoints
+    Oklab,
+    /// Interpolate lightness, chroma and hue of the Oklab space, taking the shortest way around
+    /// the hue circle
+    Oklch,
+}
+
+impl Default for ColorSpace {
+    fn default() -> Self {
+        ColorSpace::Srgb
+    }
+}
+
+/// Interpolate between two colors in `space`. Alpha is always interpolated linearly. Components
+/// are not clamped, so that easings overshooting their end and additive deltas keep their values
+pub(crate) fn lerp_color(from: Color, to: Color, scalar: f32, space: ColorSpace) -> Color {
+    let [r0, g0, b0, a0] = from.as_rgba_f32();
+    let [r1, g1, b1, a1] = to.as_rgba_f32();
+    let from = [r0, g0, b0];
+    let to = [r1, g1, b1];
+    let alpha = lerp(a0, a1, scalar);
+
+    let [r, g, b] = match space {
+        ColorSpace::Srgb => lerp3(from, to, scalar),
+        ColorSpace::LinearRgb => {
+            linear_to_srgb(lerp3(srgb_to_linear(from), srgb_to_linear(to), scalar))
+        }
+        ColorSpace::Hsl => {
+            let [h0, s0, l0] = srgb_to_hsl(from);
+            let [h1, s1, l1] = srgb_to_hsl(to);
+            let (h0, h1) = match (s0 == 0., s1 == 0.) {
+                (true, false) => (h1, h1),
+                (false, true) => (h0, h0),
+                _ => (h0, h1),
+            };
+            // saturation and lightness out of range when overshooting don't map to a color
+            hsl_to_srgb([
+                lerp_hue(h0, h1, scalar),
+                lerp(s0, s1, scalar).clamp(0., 1.),
+                lerp(l0, l1, scalar).clamp(0., 1.),
+            ])
+        }
+        ColorSpace::Oklab => oklab_to_srgb(lerp3(srgb_to_oklab(from), srgb_to_oklab(to), scalar)),
+        ColorSpace::Oklch => {
+            let [l0, c0, h0] = oklab_to_oklch(srgb_to_oklab(from));
+            let [l1, c1, h1] = oklab_to_oklch(srgb_to_oklab(to));
+            let (h0, h1) = match (c0 < ACHROMATIC, c1 < ACHROMATIC) {
+                (true, false) => (h1, h1),
+                (false, true) => (h0, h0),
+                _ => (h0, h1),
+            };
+            oklab_to_srgb(oklch_to_oklab([
+                lerp(l0, l1, scalar),
+                lerp(c0, c1, scalar),
+                lerp_hue(h0, h1, scalar),
+            ]))
+        }
+    };
+
+    Color::rgba(r, g, b, alpha)
+}
+
+/// Chroma under which an Oklch color is considered grey, and its hue meaningless
+const ACHROMATIC: f32 = 1e-4;
+
+fn lerp(from: f32, to: f32, scalar: f32) -> f32 {
+    from + (to - from) * scalar
+}
+
+fn lerp3(from: [f32; 3], to: [f32; 3], scalar: f32) -> [f32; 3] {
+    [
+        lerp(from[0], to[0], scalar),
+        lerp(from[1], to[1], scalar),
+        lerp(from[2], to[2], scalar),
+    ]
+}
+
+/// Interpolate between two hues in degrees, the shortest way around
+fn lerp_hue(from: f32, to: f32, scalar: f32) -> f32 {
+    let mut delta = (to - from) % 360.;
+    if delta > 180. {
+        delta -= 360.;
+    } else if delta < -180. {
+        delta += 360.;
+    }
+    (from + delta * scalar).rem_euclid(360.)
+}
+
+fn srgb_to_linear(color: [f32; 3]) -> [f32; 3] {
+    let convert = |c: f32| {
+        if c <= 0.04045 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    [convert(color[0]), convert(color[1]), convert(color[2])]
+}
+
+fn linear_to_srgb(color: [f32; 3]) -> [f32; 3] {
+    let convert = |c: f32| {
+        if c <= 0.0031308 {
+            c * 12.92
+        } else {
+            1.055 * c.max(0.).powf(1. / 2.4) - 0.055
+        }
+    };
+    [convert(color[0]), convert(color[1]), convert(color[2])]
+}
+
+fn srgb_to_hsl([r, g, b]: [f32; 3]) -> [f32; 3] {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let lightness = (max + min) / 2.;
+    let delta = max - min;
+    if delta == 0. {
+        return [0., 0., lightness];
+    }
+
+    let saturation = delta / (1. - (2. * lightness - 1.).abs());
+    let hue = if max == r {
+        60. * ((g - b) / delta).rem_euclid(6.)
+    } else if max == g {
+        60. * ((b - r) / delta + 2.)
+    } else {
+        60. * ((r - g) / delta + 4.)
+    };
+    [hue, saturation, lightness]
+}
+
+fn hsl_to_srgb([hue, saturation, lightness]: [f32; 3]) -> [f32; 3] {
+    let chroma = (1. - (2. * lightness - 1.).abs()) * saturation;
+    let hue = hue / 60.;
+    let x = chroma * (1. - (hue % 2. - 1.).abs());
+    let [r, g, b] = match hue as u32 {
+        0 => [chroma, x, 0.],
+        1 => [x, chroma, 0.],
+        2 => [0., chroma, x],
+        3 => [0., x, chroma],
+        4 => [x, 0., chroma],
+        _ => [chroma, 0., x],
+    };
+    let m = lightness - chroma / 2.;
+    [r + m, g + m, b + m]
+}
+
+fn srgb_to_oklab(color: [f32; 3]) -> [f32; 3] {
+    let [r, g, b] = srgb_to_linear(color);
+    let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+    let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+    let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+    [
+        0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+        1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+        0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+    ]
+}
+
+fn oklab_to_srgb([lightness, a, b]: [f32; 3]) -> [f32; 3] {
+    let l = (lightness + 0.396_337_78 * a + 0.215_803_76 * b).powi(3);
+    let m = (lightness - 0.105_561_346 * a - 0.063_854_17 * b).powi(3);
+    let s = (lightness - 0.089_484_18 * a - 1.291_485_5 * b).powi(3);
+    linear_to_srgb([
+        4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+        -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+        -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+    ])
+}
+
+fn oklab_to_oklch([lightness, a, b]: [f32; 3]) -> [f32; 3] {
+    [
+        lightness,
+        (a * a + b * b).sqrt(),
+        b.atan2(a).to_degrees().rem_euclid(360.),
+    ]
+}
+
+fn oklch_to_oklab([lightness, chroma, hue]: [f32; 3]) -> [f32; 3] {
+    let hue = hue.to_radians();
+    [lightness, chroma * hue.cos(), chroma * hue.sin()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: [f32; 3], expected: [f32; 3]) {
+        for (actual, expected) in actual.iter().zip(expected.iter()) {
+            assert!(
+                (actual - expected).abs() < 1e-4,
+                "expected {:?}, got {:?}",
+                expected,
+                actual
+            );
+        }
+    }
+
+    #[test]
+    fn linear_rgb() {
+        assert_close(srgb_to_linear([0.5, 0., 1.]), [0.214_041_1, 0., 1.]);
+        assert_close(linear_to_srgb([0.214_041_1, 0., 1.]), [0.5, 0., 1.]);
+    }
+
+    #[test]
+    fn hsl() {
+        assert_close(srgb_to_hsl([1., 0., 0.]), [0., 1., 0.5]);
+        assert_close(srgb_to_hsl([0., 0.5, 1.]), [210., 1., 0.5]);
+        assert_close(srgb_to_hsl([0.5, 0.5, 0.5]), [0., 0., 0.5]);
+        assert_close(hsl_to_srgb([120., 1., 0.25]), [0., 0.5, 0.]);
+        assert_close(hsl_to_srgb([210., 1., 0.5]), [0., 0.5, 1.]);
+    }
+
+    #[test]
+    fn oklab() {
+        assert_close(srgb_to_oklab([1., 1., 1.]), [1., 0., 0.]);
+        assert_close(
+            srgb_to_oklab([1., 0., 0.]),
+            [0.627_955, 0.224_863, 0.125_846],
+        );
+        assert_close(
+            oklab_to_srgb([0.627_955, 0.224_863, 0.125_846]),
+            [1., 0., 0.],
+        );
+    }
+
+    #[test]
+    fn oklch() {
+        assert_close(
+            oklab_to_oklch([0.627_955, 0.224_863, 0.125_846]),
+            [0.627_955, 0.257_683, 29.233_9],
+        );
+        assert_close(
+            oklch_to_oklab([0.627_955, 0.257_683, 29.233_9]),
+            [0.627_955, 0.224_863, 0.125_846],
+        );
+    }
+
+    #[test]
+    fn hue_shortest_way() {
+        assert!(lerp_hue(350., 10., 0.5).abs() < 1e-4);
+        assert!((lerp_hue(10., 350., 0.25) - 5.).abs() < 1e-4);
+    }
+
+    #[test]
+    fn overshoot_is_kept() {
+        let [r, g, b, a] = lerp_color(
+            Color::rgba(0., 0., 0., 1.),
+            Color::rgba(1., 0.5, 0., 1.),
+            1.2,
+            ColorSpace::Srgb,
+        )
+        .as_rgba_f32();
+        assert_close([r, g, b], [1.2, 0.6, 0.]);
+        assert!((a - 1.).abs() < 1e-4);
+        // negative deltas of additive easings
+        let [r, g, b, _] = lerp_color(
+            Color::rgba(0., 0., 0., 0.),
+            Color::rgba(-0.2, -0.4, 0., 0.),
+            0.5,
+            ColorSpace::Srgb,
+        )
+        .as_rgba_f32();
+        assert_close([r, g, b], [-0.1, -0.2, 0.]);
+    }
+}
@@ -0,0 +1,186 @@
+use crate::{EaseMethod, EasingComponent, EasingType};
+
+/// How a number is written by a `CounterEasing`
+#[derive(Debug, Clone, PartialEq)]
+pub struct CounterFormat {
+    /// Number of digits after the decimal separator
+    pub decimals: usize,
+    /// Separator between groups of three digits, like `,` in `1,000`
+    pub thousands_separator: Option<char>,
+    /// Separator between the integer and the decimal parts
+    pub decimal_separator: char,
+    /// Text written before the number, like a currency sign
+    pub prefix: String,
+    /// Text written after the number, like a unit
+    pub suffix: String,
+}
+
+impl Default for CounterFormat {
+    fn default() -> Self {
+        CounterFormat {
+            decimals: 0,
+            thousands_separator: None,
+            decimal_separator: '.',
+            prefix: String::new(),
+            suffix: String::new(),
+        }
+    }
+}
+
+impl CounterFormat {
+    /// Write `value` following this format
+    pub fn format(&self, value: f64) -> String {
+        let digits = format!("{:.*}", self.decimals, value.abs());
+        let (integer, decimals) = match digits.find('.') {
+            Some(index) => (&digits[..index], Some(&digits[index + 1..])),
+            None => (digits.as_str(), None),
+        };
+        // don't write `-0` when the value rounds to zero
+        let negative = value < 0. && digits.chars().any(|c| c != '0' && c != '.');
+
+        let mut result = String::new();
+        if negative {
+            result.push('-');
+        }
+        result.push_str(&self.prefix);
+        for (index, digit) in integer.chars().enumerate() {
+            if index != 0 && (integer.len() - index) % 3 == 0 {
+                if let Some(separator) = self.thousands_separator {
+                    result.push(separator);
+                }
+            }
+            result.push(digit);
+        }
+        if let Some(decimals) = decimals {
+            result.push(self.decimal_separator);
+            result.push_str(decimals);
+        }
+        result.push_str(&self.suffix);
+        result
+    }
+}
+
+enum Formatter {
+    Format(CounterFormat),
+    Custom(fn(f64) -> String),
+}
+
+/// Component easing a number and writing it in a section of a `Text`
+pub struct CounterEasing {
+    pub(crate) section: usize,
+    formatter: Formatter,
+    /// Easing of the number
+    pub easing: EasingComponent<f64>,
+}
+
+impl std::fmt::Debug for CounterEasing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CounterEasing")
+            .field("section", &self.section)
+            .field("easing", &self.easing)
+            .finish()
+    }
+}
+
+impl CounterEasing {
+    /// Count from `start` to `end` in the section `section` of the `Text`
+    pub fn new(
+        section: usize,
+        start: f64,
+        end: f64,
+        ease_function: impl Into<EaseMethod>,
+        easing_type: EasingType,
+    ) -> Self {
+        CounterEasing {
+            section,
+            formatter: Formatter::Format(CounterFormat::default()),
+            easing: EasingComponent::new(Some(start), end, ease_function, easing_type),
+        }
+    }
+
+    /// Count from `start` to `end` in the section `section` of the `Text`, without decimals
+    pub fn integer(
+        section: usize,
+        start: i64,
+        end: i64,
+        ease_function: impl Into<EaseMethod>,
+        easing_type: EasingType,
+    ) -> Self {
+        CounterEasing::new(
+            section,
+            start as f64,
+            end as f64,
+            ease_function,
+            easing_type,
+        )
+    }
+
+    /// Write the number following `format`
+    pub fn with_format(mut self, format: CounterFormat) -> Self {
+        self.formatter = Formatter::Format(format);
+        self
+    }
+
+    /// Write the number with a custom function
+    pub fn with_formatter(mut self, formatter: fn(f64) -> String) -> Self {
+        self.formatter = Formatter::Custom(formatter);
+        self
+    }
+
+    /// Number at `factor` of the easing, written with the formatter
+    pub(crate) fn text(&self, factor: f32) -> String {
+        let value = match self.easing.start {
+            Some(ref start) => start.0 + (self.easing.end.0 - start.0) * factor as f64,
+            None => self.easing.end.0,
+        };
+        match self.formatter {
+            Formatter::Format(ref format) => format.format(value),
+            Formatter::Custom(formatter) => formatter(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_negative_zero() {
+        assert_eq!(CounterFormat::default().format(-0.4), "0");
+        let format = CounterFormat {
+            decimals: 2,
+            ..Default::default()
+        };
+        assert_eq!(format.format(-0.004), "0.00");
+        assert_eq!(CounterFormat::default().format(-3.), "-3");
+    }
+
+    #[test]
+    fn thousands_grouping() {
+        let format = CounterFormat {
+            thousands_separator: Some(','),
+            ..Default::default()
+        };
+        assert_eq!(format.format(999.), "999");
+        assert_eq!(format.format(100_000.), "100,000");
+        assert_eq!(format.format(1_234_567.), "1,234,567");
+    }
+
+    #[test]
+    fn decimals() {
+        let format = CounterFormat {
+            decimals: 2,
+            decimal_separator: ',',
+            ..Default::default()
+        };
+        assert_eq!(format.format(12.3456), "12,35");
+        assert_eq!(format.format(7.), "7,00");
+        let format = CounterFormat {
+            decimals: 2,
+            thousands_separator: Some(','),
+            prefix: "$".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(format.format(-1234.5), "-$1,234.50");
+    }
+}
@@ -0,0 +1,256 @@
+use bevy::{ecs::component::Component, prelude::*};
+
+use crate::{EaseMethod, EasingComponent, EasingType};
+
+/// Trait for a field of a component that can be eased on its own. Easings on different fields of
+/// the same component have their own timers and don't overwrite each other
+pub trait EaseField: Send + Sync + Sized + 'static {
+    /// Component holding the field
+    type Component: Component;
+    /// Type of the field
+    type Value: Send + Sync + 'static;
+
+    /// Read the field from the component
+    fn get(component: &Self::Component) -> Self::Value;
+
+    /// Write the field in the component
+    fn set(component: &mut Self::Component, value: Self::Value);
+
+    /// Create a new easing of this field. If no start is provided, it will use the current value
+    /// of the field
+    fn ease(
+        start: Option<Self::Value>,
+        end: Self::Value,
+        ease_function: impl Into<EaseMethod>,
+        easing_type: EasingType,
+    ) -> FieldEasing<Self> {
+        FieldEasing {
+            easing: EasingComponent::new(start, end, ease_function, easing_type),
+        }
+    }
+
+    /// Create a new easing of this field with its current value as a starting point
+    fn ease_to(
+        end: Self::Value,
+        ease_function: impl Into<EaseMethod>,
+        easing_type: EasingType,
+    ) -> FieldEasing<Self> {
+        Self::ease(None, end, ease_function, easing_type)
+    }
+}
+
+/// Component to control an easing of the field `F` of a component
+pub struct FieldEasing<F: EaseField> {
+    /// Easing of the field
+    pub easing: EasingComponent<F::Value>,
+}
+
+impl<F: EaseField> std::fmt::Debug for FieldEasing<F>
+where
+    F::Value: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FieldEasing")
+            .field("easing", &self.easing)
+            .finish()
+    }
+}
+
+/// Field `translation` of a `Transform`
+#[derive(Debug, Clone, Copy)]
+pub struct TransformTranslation;
+
+impl EaseField for TransformTranslation {
+    type Component = Transform;
+    type Value = Vec3;
+
+    fn get(component: &Transform) -> Vec3 {
+        component.translation
+    }
+
+    fn set(component: &mut Transform, value: Vec3) {
+        component.translation = value;
+    }
+}
+
+/// Field `rotation` of a `Transform`
+#[derive(Debug, Clone, Copy)]
+pub struct TransformRotation;
+
+impl EaseField for TransformRotation {
+    type Component = Transform;
+    type Value = Quat;
+
+    fn get(component: &Transform) -> Quat {
+        component.rotation
+    }
+
+    fn set(component: &mut Transform, value: Quat) {
+        component.rotation = value;
+    }
+}
+
+/// A rotation of `angle` radians around `axis`. Unlike a quaternion, it can hold several turns
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AxisAngle {
+    /// Axis of the rotation, normalized
+    pub axis: Vec3,
+    /// Angle of the rotation, in radians
+    pub angle: f32,
+}
+
+impl AxisAngle {
+    /// Create a rotation of `angle` radians around `axis`
+    pub fn new(axis: Vec3, angle: f32) -> Self {
+        AxisAngle {
+            axis: axis.normalize(),
+            angle,
+        }
+    }
+
+    /// Create a rotation of `turns` full turns around `axis`
+    pub fn from_turns(axis: Vec3, turns: f32) -> Self {
+        AxisAngle::new(axis, turns * 2. * std::f32::consts::PI)
+    }
+}
+
+/// Field `rotation` of a `Transform`, as an `AxisAngle`. The angle is interpolated, so an easing
+/// can spin the entity several times, for example from `0.` to `AxisAngle::from_turns(Vec3::Z, 2.)`
+#[derive(Debug, Clone, Copy)]
+pub struct TransformAxisAngle;
+
+impl EaseField for TransformAxisAngle {
+    type Component = Transform;
+    type Value = AxisAngle;
+
+    fn get(component: &Transform) -> AxisAngle {
+        let (axis, angle) = component.rotation.to_axis_angle();
+        // keep the angle between -π and π, the same rotation with the least spin
+        let angle = if angle > std::f32::consts::PI {
+            angle - 2. * std::f32::consts::PI
+        } else {
+            angle
+        };
+        AxisAngle { axis, angle }
+    }
+
+    fn set(component: &mut Transform, value: AxisAngle) {
+        component.rotation = Quat::from_axis_angle(value.axis, value.angle);
+    }
+}
+
+/// Field `scale` of a `Transform`
+#[derive(Debug, Clone, Copy)]
+pub struct TransformScale;
+
+impl EaseField for TransformScale {
+    type Component = Transform;
+    type Value = Vec3;
+
+    fn get(component: &Transform) -> Vec3 {
+        component.scale
+    }
+
+    fn set(component: &mut Transform, value: Vec3) {
+        component.scale = value;
+    }
+}
+
+/// Field `size` of a `Sprite`
+#[derive(Debug, Clone, Copy)]
+pub struct SpriteSize;
+
+impl EaseField for SpriteSize {
+    type Component = Sprite;
+    type Value = Vec2;
+
+    fn get(component: &Sprite) -> Vec2 {
+        component.size
+    }
+
+    fn set(component: &mut Sprite, value: Vec2) {
+        component.size = value;
+    }
+}
+
+/// Accessors to a value of type `V` inside the component `C`, to ease it without implementing
+/// `Lerp` for the whole component. Closures that don't capture anything can be used
+pub struct Lens<C, V> {
+    get: fn(&C) -> V,
+    set: fn(&mut C, V),
+}
+
+impl<C, V> Clone for Lens<C, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C, V> Copy for Lens<C, V> {}
+
+impl<C, V> std::fmt::Debug for Lens<C, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Lens").finish()
+    }
+}
+
+impl<C, V> Lens<C, V> {
+    /// Create a new lens from its accessors
+    pub fn new(get: fn(&C) -> V, set: fn(&mut C, V)) -> Self {
+        Lens { get, set }
+    }
+
+    /// Create a new easing of the value. If no start is provided, it will use the current value
+    /// from the component
+    pub fn ease(
+        self,
+        start: Option<V>,
+        end: V,
+        ease_function: impl Into<EaseMethod>,
+        easing_type: EasingType,
+    ) -> LensEasing<C, V> {
+        LensEasing {
+            lens: self,
+            easing: EasingComponent::new(start, end, ease_function, easing_type),
+        }
+    }
+
+    /// Create a new easing of the value with its current value as a starting point
+    pub fn ease_to(
+        self,
+        end: V,
+        ease_function: impl Into<EaseMethod>,
+        easing_type: EasingType,
+    ) -> LensEasing<C, V> {
+        self.ease(None, end, ease_function, easing_type)
+    }
+
+    pub(crate) fn get(&self, component: &C) -> V {
+        (self.get)(component)
+    }
+
+    pub(crate) fn set(&self, component: &mut C, value: V) {
+        (self.set)(component, value)
+    }
+}
+
+/// Component to control an easing through a `Lens`
+pub struct LensEasing<C, V> {
+    lens: Lens<C, V>,
+    /// Easing of the value
+    pub easing: EasingComponent<V>,
+}
+
+impl<C, V: std::fmt::Debug> std::fmt::Debug for LensEasing<C, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LensEasing")
+            .field("easing", &self.easing)
+            .finish()
+    }
+}
+
+impl<C, V> LensEasing<C, V> {
+    pub(crate) fn lens(&self) -> Lens<C, V> {
+        self.lens
+    }
+}
@@ -2,7 +2,10 @@ use bevy::prelude::*;
 
 use interpolation::Lerp;
 
-use crate::{EaseValue, IntermediateLerp};
+use crate::{
+    color::lerp_color, ApplyEased, AxisAngle, DiscreteSwitch, EaseValue, EasingOptions, FieldMask,
+    IntermediateLerp, LerpWith, RotationMode,
+};
 
 impl Lerp for EaseValue<Sprite> {
     type Scalar = f32;
@@ -10,11 +13,7 @@ impl Lerp for EaseValue<Sprite> {
     fn lerp(&self, other: &Self, scalar: &Self::Scalar) -> Self {
         EaseValue(Sprite {
             size: self.0.size + (other.0.size - self.0.size) * *scalar,
-            resize_mode: match self.0.resize_mode {
-                SpriteResizeMode::Manual => SpriteResizeMode::Manual,
-                SpriteResizeMode::Automatic => SpriteResizeMode::Automatic,
-            },
-            ..Default::default()
+            ..self.0.clone()
         })
     }
 }
@@ -23,10 +22,24 @@ impl Lerp for EaseValue<Transform> {
     type Scalar = f32;
 
     fn lerp(&self, other: &Self, scalar: &Self::Scalar) -> Self {
+        self.lerp_with(other, scalar, *scalar, &EasingOptions::default())
+    }
+}
+
+impl LerpWith for EaseValue<Transform> {
+    fn lerp_with(
+        &self,
+        other: &Self,
+        scalar: &f32,
+        progress: f32,
+        options: &EasingOptions,
+    ) -> Self {
         EaseValue(Transform {
             translation: self.0.translation.lerp(other.0.translation, *scalar),
             scale: self.0.scale.lerp(other.0.scale, *scalar),
-            rotation: self.0.rotation.lerp(other.0.rotation, *scalar),
+            rotation: EaseValue(self.0.rotation)
+                .lerp_with(&EaseValue(other.0.rotation), scalar, progress, options)
+                .0,
         })
     }
 }
@@ -35,9 +48,21 @@ impl Lerp for EaseValue<ColorMaterial> {
     type Scalar = f32;
 
     fn lerp(&self, other: &Self, scalar: &Self::Scalar) -> Self {
+        self.lerp_with(other, scalar, *scalar, &EasingOptions::default())
+    }
+}
+
+impl LerpWith for EaseValue<ColorMaterial> {
+    fn lerp_with(
+        &self,
+        other: &Self,
+        scalar: &f32,
+        _progress: f32,
+        options: &EasingOptions,
+    ) -> Self {
         if self.0.texture.is_none() {
             EaseValue(ColorMaterial {
-                color: self.0.color + (other.0.color + (self.0.color * -1.)) * *scalar,
+                color: lerp_color(self.0.color, other.0.color, *scalar, options.color_space),
                 texture: None,
             })
         } else {
@@ -53,29 +78,54 @@ impl Lerp for EaseValue<Style> {
     type Scalar = f32;
 
     fn lerp(&self, other: &Self, scalar: &Self::Scalar) -> Self {
+        self.lerp_with(other, scalar, *scalar, &EasingOptions::default())
+    }
+}
+
+impl LerpWith for EaseValue<Style> {
+    fn lerp_with(
+        &self,
+        other: &Self,
+        scalar: &f32,
+        progress: f32,
+        options: &EasingOptions,
+    ) -> Self {
+        let (from, to, scalar) = (&self.0, &other.0, *scalar);
+        let reference = options.reference_size;
+        let switched = options.switch.switched(progress);
+        // percentages of margin, padding and border are relative to the width on all sides
+        let width = reference.map(|size| Vec2::new(size.x, size.x));
+        // percentages of flex basis are relative to the main axis of the parent container
+        let direction = options.reference_direction.unwrap_or(FlexDirection::Row);
+        let main_axis = reference.map(|size| match direction {
+            FlexDirection::Row | FlexDirection::RowReverse => size.x,
+            FlexDirection::Column | FlexDirection::ColumnReverse => size.y,
+        });
         EaseValue(Style {
-            position: EaseValue(self.0.position)
-                .lerp(&EaseValue(other.0.position), scalar)
-                .0,
-            margin: EaseValue(self.0.margin)
-                .lerp(&EaseValue(other.0.margin), scalar)
-                .0,
-            padding: EaseValue(self.0.padding)
-                .lerp(&EaseValue(other.0.padding), scalar)
-                .0,
-            border: EaseValue(self.0.border)
-                .lerp(&EaseValue(other.0.border), scalar)
-                .0,
-            size: EaseValue(self.0.size)
-                .lerp(&EaseValue(other.0.size), scalar)
-                .0,
-            min_size: EaseValue(self.0.min_size)
-                .lerp(&EaseValue(other.0.min_size), scalar)
-                .0,
-            max_size: EaseValue(self.0.max_size)
-                .lerp(&EaseValue(other.0.max_size), scalar)
-                .0,
-            ..self.0
+            display: pick(switched, from.display, to.display),
+            position_type: pick(switched, from.position_type, to.position_type),
+            direction: pick(switched, from.direction, to.direction),
+            flex_direction: pick(switched, from.flex_direction, to.flex_direction),
+            flex_wrap: pick(switched, from.flex_wrap, to.flex_wrap),
+            align_items: pick(switched, from.align_items, to.align_items),
+            align_self: pick(switched, from.align_self, to.align_self),
+            align_content: pick(switched, from.align_content, to.align_content),
+            justify_content: pick(switched, from.justify_content, to.justify_content),
+            position: lerp_rect(from.position, to.position, scalar, reference, switched),
+            margin: lerp_rect(from.margin, to.margin, scalar, width, switched),
+            padding: lerp_rect(from.padding, to.padding, scalar, width, switched),
+            border: lerp_rect(from.border, to.border, scalar, width, switched),
+            flex_grow: Lerp::lerp(&from.flex_grow, &to.flex_grow, &scalar),
+            flex_shrink: Lerp::lerp(&from.flex_shrink, &to.flex_shrink, &scalar),
+            flex_basis: lerp_val(from.flex_basis, to.flex_basis, scalar, main_axis, switched),
+            size: lerp_size(from.size, to.size, scalar, reference, switched),
+            min_size: lerp_size(from.min_size, to.min_size, scalar, reference, switched),
+            max_size: lerp_size(from.max_size, to.max_size, scalar, reference, switched),
+            aspect_ratio: match (from.aspect_ratio, to.aspect_ratio) {
+                (Some(from), Some(to)) => Some(Lerp::lerp(&from, &to, &scalar)),
+                (from, to) => pick(switched, from, to),
+            },
+            overflow: pick(switched, from.overflow, to.overflow),
         })
     }
 }
@@ -84,20 +134,13 @@ impl Lerp for EaseValue<Rect<Val>> {
     type Scalar = f32;
 
     fn lerp(&self, other: &Self, scalar: &Self::Scalar) -> Self {
-        EaseValue(Rect {
-            left: EaseValue(self.0.left)
-                .lerp(&EaseValue(other.0.left), scalar)
-                .0,
-            right: EaseValue(self.0.right)
-                .lerp(&EaseValue(other.0.right), scalar)
-                .0,
-            top: EaseValue(self.0.top)
-                .lerp(&EaseValue(other.0.top), scalar)
-                .0,
-            bottom: EaseValue(self.0.bottom)
-                .lerp(&EaseValue(other.0.bottom), scalar)
-                .0,
-        })
+        EaseValue(lerp_rect(
+            self.0,
+            other.0,
+            *scalar,
+            None,
+            DiscreteSwitch::default().switched(*scalar),
+        ))
     }
 }
 
@@ -105,14 +148,13 @@ impl Lerp for EaseValue<Size<Val>> {
     type Scalar = f32;
 
     fn lerp(&self, other: &Self, scalar: &Self::Scalar) -> Self {
-        EaseValue(Size {
-            width: EaseValue(self.0.width)
-                .lerp(&EaseValue(other.0.width), scalar)
-                .0,
-            height: EaseValue(self.0.height)
-                .lerp(&EaseValue(other.0.height), scalar)
-                .0,
-        })
+        EaseValue(lerp_size(
+            self.0,
+            other.0,
+            *scalar,
+            None,
+            DiscreteSwitch::default().switched(*scalar),
+        ))
     }
 }
 
@@ -120,33 +162,124 @@ impl Lerp for EaseValue<Val> {
     type Scalar = f32;
 
     fn lerp(&self, other: &Self, scalar: &Self::Scalar) -> Self {
-        match (self.0, other.0) {
-            (Val::Percent(self_val), Val::Percent(other_val)) => {
-                EaseValue(Val::Percent(Lerp::lerp(&self_val, &other_val, scalar)))
-            }
-            (Val::Px(self_val), Val::Px(other_val)) => {
-                EaseValue(Val::Px(Lerp::lerp(&self_val, &other_val, scalar)))
-            }
-            _ => EaseValue(self.0),
+        EaseValue(lerp_val(
+            self.0,
+            other.0,
+            *scalar,
+            None,
+            DiscreteSwitch::default().switched(*scalar),
+        ))
+    }
+}
+
+/// Pick the end value of something that can't be interpolated once `switched`
+fn pick<T>(switched: bool, from: T, to: T) -> T {
+    if switched {
+        to
+    } else {
+        from
+    }
+}
+
+/// Interpolate between two `Rect<Val>`, with `reference` the size against which percentages of
+/// horizontal and vertical sides are resolved
+fn lerp_rect(
+    from: Rect<Val>,
+    to: Rect<Val>,
+    scalar: f32,
+    reference: Option<Vec2>,
+    switched: bool,
+) -> Rect<Val> {
+    let horizontal = reference.map(|size| size.x);
+    let vertical = reference.map(|size| size.y);
+    Rect {
+        left: lerp_val(from.left, to.left, scalar, horizontal, switched),
+        right: lerp_val(from.right, to.right, scalar, horizontal, switched),
+        top: lerp_val(from.top, to.top, scalar, vertical, switched),
+        bottom: lerp_val(from.bottom, to.bottom, scalar, vertical, switched),
+    }
+}
+
+/// Interpolate between two `Size<Val>`, with `reference` the size against which percentages are
+/// resolved
+fn lerp_size(
+    from: Size<Val>,
+    to: Size<Val>,
+    scalar: f32,
+    reference: Option<Vec2>,
+    switched: bool,
+) -> Size<Val> {
+    Size {
+        width: lerp_val(
+            from.width,
+            to.width,
+            scalar,
+            reference.map(|size| size.x),
+            switched,
+        ),
+        height: lerp_val(
+            from.height,
+            to.height,
+            scalar,
+            reference.map(|size| size.y),
+            switched,
+        ),
+    }
+}
+
+/// Interpolate between two `Val`. When they are in different units, the start value is converted
+/// to the unit of the end value using `reference`, the length a percentage is relative to. Values
+/// that can't be converted, like `Val::Auto`, change from start to end once `switched`
+fn lerp_val(from: Val, to: Val, scalar: f32, reference: Option<f32>, switched: bool) -> Val {
+    match (from, to, reference.filter(|reference| *reference > 0.)) {
+        (Val::Px(from), Val::Px(to), _) => Val::Px(lerp_length(from, to, scalar)),
+        (Val::Percent(from), Val::Percent(to), _) => Val::Percent(lerp_length(from, to, scalar)),
+        (Val::Px(from), Val::Percent(to), Some(reference)) => {
+            Val::Percent(lerp_length(from / reference * 100., to, scalar))
+        }
+        (Val::Percent(from), Val::Px(to), Some(reference)) => {
+            Val::Px(lerp_length(from * reference / 100., to, scalar))
         }
+        _ => pick(switched, from, to),
     }
 }
 
+/// Interpolate between two lengths, landing exactly on `to` when `scalar` is 1 so that a finished
+/// easing doesn't leave a converted value slightly off its target
+fn lerp_length(from: f32, to: f32, scalar: f32) -> f32 {
+    from * (1. - scalar) + to * scalar
+}
+
 impl Lerp for EaseValue<Color> {
     type Scalar = f32;
 
     fn lerp(&self, other: &Self, scalar: &Self::Scalar) -> Self {
-        EaseValue(self.0 + (other.0 + (self.0 * -1.)) * *scalar)
+        self.lerp_with(other, scalar, *scalar, &EasingOptions::default())
+    }
+}
+
+impl LerpWith for EaseValue<Color> {
+    fn lerp_with(
+        &self,
+        other: &Self,
+        scalar: &f32,
+        _progress: f32,
+        options: &EasingOptions,
+    ) -> Self {
+        EaseValue(lerp_color(self.0, other.0, *scalar, options.color_space))
     }
 }
 
 impl IntermediateLerp for ColorMaterial {
-    fn lerp(start: &EaseValue<&Self>, end: &EaseValue<&Self>, scalar: &f32) -> Self {
+    fn lerp(
+        start: &EaseValue<&Self>,
+        end: &EaseValue<&Self>,
+        scalar: &f32,
+        options: &EasingOptions,
+    ) -> Self {
         if start.0.texture.is_none() {
             ColorMaterial {
-                color: EaseValue(start.0.color)
-                    .lerp(&EaseValue(end.0.color), scalar)
-                    .0,
+                color: lerp_color(start.0.color, end.0.color, *scalar, options.color_space),
                 texture: None,
             }
         } else {
@@ -157,3 +290,459 @@ impl IntermediateLerp for ColorMaterial {
         }
     }
 }
+
+impl Lerp for EaseValue<Vec2> {
+    type Scalar = f32;
+
+    fn lerp(&self, other: &Self, scalar: &Self::Scalar) -> Self {
+        EaseValue(self.0.lerp(other.0, *scalar))
+    }
+}
+
+impl Lerp for EaseValue<Vec3> {
+    type Scalar = f32;
+
+    fn lerp(&self, other: &Self, scalar: &Self::Scalar) -> Self {
+        EaseValue(self.0.lerp(other.0, *scalar))
+    }
+}
+
+impl Lerp for EaseValue<Quat> {
+    type Scalar = f32;
+
+    fn lerp(&self, other: &Self, scalar: &Self::Scalar) -> Self {
+        self.lerp_with(other, scalar, *scalar, &EasingOptions::default())
+    }
+}
+
+impl LerpWith for EaseValue<Quat> {
+    fn lerp_with(
+        &self,
+        other: &Self,
+        scalar: &f32,
+        _progress: f32,
+        options: &EasingOptions,
+    ) -> Self {
+        EaseValue(slerp(self.0, other.0, *scalar, options.rotation))
+    }
+}
+
+impl Lerp for EaseValue<AxisAngle> {
+    type Scalar = f32;
+
+    fn lerp(&self, other: &Self, scalar: &Self::Scalar) -> Self {
+        // `(axis, angle)` and `(-axis, -angle)` are the same rotation, start from the one whose
+        // axis is on the side of the end axis so that the axis doesn't flip through zero
+        let from = if self.0.axis.dot(other.0.axis) < 0. {
+            AxisAngle {
+                axis: -self.0.axis,
+                angle: -self.0.angle,
+            }
+        } else {
+            self.0
+        };
+        // the axis of a rotation without angle doesn't matter, use the one of the other side
+        let axis = if from.angle.abs() < std::f32::EPSILON {
+            other.0.axis
+        } else if other.0.angle.abs() < std::f32::EPSILON {
+            from.axis
+        } else {
+            let axis = from.axis.lerp(other.0.axis, *scalar);
+            if axis.length_squared() > std::f32::EPSILON {
+                axis.normalize()
+            } else {
+                other.0.axis
+            }
+        };
+        EaseValue(AxisAngle {
+            axis,
+            angle: from.angle + (other.0.angle - from.angle) * *scalar,
+        })
+    }
+}
+
+/// Interpolate between two rotations. `q` and `-q` are the same rotation, so the sign of `to` is
+/// chosen to go the requested way around
+fn slerp(from: Quat, to: Quat, scalar: f32, mode: RotationMode) -> Quat {
+    let mut to = to;
+    let mut dot = from.dot(to);
+    let flip = match mode {
+        RotationMode::Shortest | RotationMode::Nlerp => dot < 0.,
+        RotationMode::Longest => dot > 0.,
+    };
+    if flip {
+        to = to * -1.;
+        dot = -dot;
+    }
+
+    if mode == RotationMode::Nlerp || dot > 0.9995 {
+        return (from * (1. - scalar) + to * scalar).normalize();
+    }
+    if dot < -0.9995 {
+        // nearly a full turn, with no single arc between both ends: go through a rotation
+        // orthogonal to `from`, which is also nearly orthogonal to `to`
+        let [x, y, z, w]: [f32; 4] = from.into();
+        let orthogonal = Quat::from_xyzw(-y, x, -w, z);
+        return if scalar < 0.5 {
+            great_arc(from, orthogonal, scalar * 2.)
+        } else {
+            great_arc(orthogonal, to, scalar * 2. - 1.)
+        };
+    }
+    great_arc(from, to, scalar)
+}
+
+/// Spherical interpolation along the arc from `from` to `to`, without changing their signs
+fn great_arc(from: Quat, to: Quat, scalar: f32) -> Quat {
+    let theta = from.dot(to).clamp(-1., 1.).acos();
+    let sin_theta = theta.sin();
+    from * (((1. - scalar) * theta).sin() / sin_theta) + to * ((scalar * theta).sin() / sin_theta)
+}
+
+impl LerpWith for EaseValue<Sprite> {
+    fn lerp_with(
+        &self,
+        other: &Self,
+        scalar: &f32,
+        _progress: f32,
+        _options: &EasingOptions,
+    ) -> Self {
+        self.lerp(other, scalar)
+    }
+}
+
+impl LerpWith for EaseValue<Vec2> {
+    fn lerp_with(
+        &self,
+        other: &Self,
+        scalar: &f32,
+        _progress: f32,
+        _options: &EasingOptions,
+    ) -> Self {
+        self.lerp(other, scalar)
+    }
+}
+
+impl LerpWith for EaseValue<Vec3> {
+    fn lerp_with(
+        &self,
+        other: &Self,
+        scalar: &f32,
+        _progress: f32,
+        _options: &EasingOptions,
+    ) -> Self {
+        self.lerp(other, scalar)
+    }
+}
+
+impl LerpWith for EaseValue<AxisAngle> {
+    fn lerp_with(
+        &self,
+        other: &Self,
+        scalar: &f32,
+        _progress: f32,
+        _options: &EasingOptions,
+    ) -> Self {
+        self.lerp(other, scalar)
+    }
+}
+
+impl Lerp for EaseValue<Text> {
+    type Scalar = f32;
+
+    fn lerp(&self, other: &Self, scalar: &Self::Scalar) -> Self {
+        self.lerp_with(other, scalar, *scalar, &EasingOptions::default())
+    }
+}
+
+impl LerpWith for EaseValue<Text> {
+    fn lerp_with(
+        &self,
+        other: &Self,
+        scalar: &f32,
+        _progress: f32,
+        options: &EasingOptions,
+    ) -> Self {
+        EaseValue(Text {
+            sections: self
+                .0
+                .sections
+                .iter()
+                .enumerate()
+                .map(|(index, from)| match other.0.sections.get(index) {
+                    Some(to) => TextSection {
+                        value: from.value.clone(),
+                        style: TextStyle {
+                            font: from.style.font.clone(),
+                            font_size: Lerp::lerp(
+                                &from.style.font_size,
+                                &to.style.font_size,
+                                scalar,
+                            ),
+                            color: lerp_color(
+                                from.style.color,
+                                to.style.color,
+                                *scalar,
+                                options.color_space,
+                            ),
+                        },
+                    },
+                    None => from.clone(),
+                })
+                .collect(),
+            alignment: self.0.alignment,
+        })
+    }
+}
+
+impl ApplyEased for Text {
+    fn apply_eased(&mut self, value: Self, _options: &EasingOptions) {
+        for (section, eased) in self.sections.iter_mut().zip(value.sections.into_iter()) {
+            section.style.font_size = eased.style.font_size;
+            section.style.color = eased.style.color;
+        }
+    }
+}
+
+impl ApplyEased for ColorMaterial {
+    fn apply_eased(&mut self, value: Self, _options: &EasingOptions) {
+        self.color = value.color;
+    }
+}
+
+impl ApplyEased for Color {
+    fn apply_eased(&mut self, value: Self, _options: &EasingOptions) {
+        *self = value;
+    }
+}
+
+impl ApplyEased for Sprite {
+    fn apply_eased(&mut self, value: Self, options: &EasingOptions) {
+        if options.fields.contains(FieldMask::SIZE) {
+            self.size = value.size;
+        }
+    }
+}
+
+impl ApplyEased for Transform {
+    fn apply_eased(&mut self, value: Self, options: &EasingOptions) {
+        if options.fields.contains(FieldMask::TRANSLATION) {
+            self.translation = value.translation;
+        }
+        if options.fields.contains(FieldMask::ROTATION) {
+            self.rotation = value.rotation;
+        }
+        if options.fields.contains(FieldMask::SCALE) {
+            self.scale = value.scale;
+        }
+    }
+}
+
+impl ApplyEased for Style {
+    fn apply_eased(&mut self, value: Self, options: &EasingOptions) {
+        let fields = options.fields;
+        if fields.contains(FieldMask::LAYOUT) {
+            self.display = value.display;
+            self.position_type = value.position_type;
+            self.direction = value.direction;
+            self.flex_direction = value.flex_direction;
+            self.flex_wrap = value.flex_wrap;
+            self.align_items = value.align_items;
+            self.align_self = value.align_self;
+            self.align_content = value.align_content;
+            self.justify_content = value.justify_content;
+            self.overflow = value.overflow;
+        }
+        if fields.contains(FieldMask::POSITION) {
+            self.position = value.position;
+        }
+        if fields.contains(FieldMask::MARGIN) {
+            self.margin = value.margin;
+        }
+        if fields.contains(FieldMask::PADDING) {
+            self.padding = value.padding;
+        }
+        if fields.contains(FieldMask::BORDER) {
+            self.border = value.border;
+        }
+        if fields.contains(FieldMask::FLEX) {
+            self.flex_grow = value.flex_grow;
+            self.flex_shrink = value.flex_shrink;
+            self.flex_basis = value.flex_basis;
+        }
+        if fields.contains(FieldMask::SIZE) {
+            self.size = value.size;
+        }
+        if fields.contains(FieldMask::MIN_SIZE) {
+            self.min_size = value.min_size;
+        }
+        if fields.contains(FieldMask::MAX_SIZE) {
+            self.max_size = value.max_size;
+        }
+        if fields.contains(FieldMask::ASPECT_RATIO) {
+            self.aspect_ratio = value.aspect_ratio;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    fn same_rotation(actual: Quat, expected: Quat) -> bool {
+        actual.dot(expected).abs() > 0.9999
+    }
+
+    #[test]
+    fn slerp_reaches_both_ends() {
+        let targets = [
+            Quat::from_rotation_z(3_f32.to_radians()),
+            Quat::from_rotation_z(FRAC_PI_2),
+            Quat::from_rotation_z(PI),
+            Quat::IDENTITY * -1.,
+        ];
+        for mode in [
+            RotationMode::Shortest,
+            RotationMode::Nlerp,
+            RotationMode::Longest,
+        ]
+        .iter()
+        {
+            for target in targets.iter() {
+                let start = slerp(Quat::IDENTITY, *target, 0., *mode);
+                let end = slerp(Quat::IDENTITY, *target, 1., *mode);
+                assert!(same_rotation(start, Quat::IDENTITY));
+                assert!(same_rotation(end, *target), "{:?} to {:?}", mode, target);
+            }
+        }
+    }
+
+    #[test]
+    fn slerp_quarter_turn() {
+        let target = Quat::from_rotation_z(FRAC_PI_2);
+        let halfway = Quat::from_rotation_z(FRAC_PI_4);
+        assert!(same_rotation(
+            slerp(Quat::IDENTITY, target, 0.5, RotationMode::Shortest),
+            halfway
+        ));
+        assert!(same_rotation(
+            slerp(Quat::IDENTITY, target, 0.5, RotationMode::Nlerp),
+            halfway
+        ));
+        assert!(same_rotation(
+            slerp(Quat::IDENTITY, target, 0.5, RotationMode::Longest),
+            Quat::from_rotation_z(-3. * FRAC_PI_4)
+        ));
+    }
+
+    #[test]
+    fn slerp_nearly_equal() {
+        let target = Quat::from_rotation_z(3_f32.to_radians());
+        assert!(same_rotation(
+            slerp(Quat::IDENTITY, target, 0.5, RotationMode::Shortest),
+            Quat::from_rotation_z(1.5_f32.to_radians())
+        ));
+        // the long way around is half a turn away from both ends at half time
+        let halfway = slerp(Quat::IDENTITY, target, 0.5, RotationMode::Longest);
+        assert!(halfway.dot(Quat::IDENTITY).abs() < 0.05);
+    }
+
+    #[test]
+    fn slerp_opposite() {
+        let target = Quat::IDENTITY * -1.;
+        assert!(same_rotation(
+            slerp(Quat::IDENTITY, target, 0.5, RotationMode::Shortest),
+            Quat::IDENTITY
+        ));
+        let halfway = slerp(Quat::IDENTITY, target, 0.5, RotationMode::Longest);
+        assert!(halfway.dot(Quat::IDENTITY).abs() < 1e-4);
+    }
+
+    #[test]
+    fn val_converts_between_units() {
+        assert_eq!(
+            lerp_val(Val::Px(50.), Val::Percent(50.), 0.5, Some(200.), false),
+            Val::Percent(37.5)
+        );
+        assert_eq!(
+            lerp_val(Val::Percent(50.), Val::Px(50.), 0.5, Some(200.), false),
+            Val::Px(75.)
+        );
+    }
+
+    #[test]
+    fn val_switches_discrete_values() {
+        let switch_at = |switch: DiscreteSwitch, progress: f32| {
+            lerp_val(
+                Val::Auto,
+                Val::Px(10.),
+                progress,
+                None,
+                switch.switched(progress),
+            )
+        };
+        assert_eq!(switch_at(DiscreteSwitch::Start, 0.), Val::Auto);
+        assert_eq!(switch_at(DiscreteSwitch::Start, 0.1), Val::Px(10.));
+        assert_eq!(switch_at(DiscreteSwitch::Midpoint, 0.4), Val::Auto);
+        assert_eq!(switch_at(DiscreteSwitch::Midpoint, 0.5), Val::Px(10.));
+        assert_eq!(switch_at(DiscreteSwitch::End, 0.9), Val::Auto);
+        assert_eq!(switch_at(DiscreteSwitch::End, 1.), Val::Px(10.));
+    }
+
+    #[test]
+    fn val_without_reference() {
+        for reference in [None, Some(0.)].iter() {
+            assert_eq!(
+                lerp_val(Val::Px(50.), Val::Percent(50.), 0.4, *reference, false),
+                Val::Px(50.)
+            );
+            assert_eq!(
+                lerp_val(Val::Px(50.), Val::Percent(50.), 0.6, *reference, true),
+                Val::Percent(50.)
+            );
+        }
+    }
+
+    #[test]
+    fn val_ends_on_target() {
+        assert_eq!(
+            lerp_val(Val::Px(33.3), Val::Percent(10.1), 1., Some(7.), false),
+            Val::Percent(10.1)
+        );
+        assert_eq!(
+            lerp_val(Val::Percent(33.3), Val::Px(10.1), 1., Some(7.), false),
+            Val::Px(10.1)
+        );
+        assert_eq!(
+            lerp_val(Val::Px(0.1), Val::Px(0.7), 1., None, false),
+            Val::Px(0.7)
+        );
+    }
+
+    fn spin_to_two_turns(from: AxisAngle, start_angle: f32) {
+        let to = EaseValue(AxisAngle::from_turns(Vec3::Z, 2.));
+        for step in 0..=10 {
+            let scalar = step as f32 / 10.;
+            let eased = EaseValue(from).lerp(&to, &scalar).0;
+            assert!(eased.axis.abs_diff_eq(Vec3::Z, 1e-6), "{:?}", eased);
+            let expected = start_angle + (to.0.angle - start_angle) * scalar;
+            assert!((eased.angle - expected).abs() < 1e-4, "{:?}", eased);
+        }
+    }
+
+    #[test]
+    fn axis_angle_from_opposite_axis() {
+        spin_to_two_turns(AxisAngle::new(-Vec3::Z, 0.5), -0.5);
+    }
+
+    #[test]
+    fn axis_angle_from_no_angle() {
+        spin_to_two_turns(AxisAngle::new(Vec3::X, 0.), 0.);
+    }
+
+    #[test]
+    fn axis_angle_on_same_axis() {
+        spin_to_two_turns(AxisAngle::new(Vec3::Z, FRAC_PI_2), FRAC_PI_2);
+    }
+}
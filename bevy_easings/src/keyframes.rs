@@ -0,0 +1,133 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{EaseMethod, EaseValue, EasingOptions, EasingState, EasingType, MyEaser, Playback};
+
+struct Keyframe<T> {
+    time: f32,
+    value: EaseValue<T>,
+    ease_function: EaseMethod,
+}
+
+/// Component to control an easing through several keyframes. The whole track follows the
+/// `EasingType`, so it loops or ping pongs over all its keyframes
+pub struct KeyframeEasing<T> {
+    keyframes: Vec<Keyframe<T>>,
+    duration: Duration,
+    options: EasingOptions,
+    /// Size of the parent node, used when `options` doesn't set a reference size
+    pub(crate) parent_size: Option<Vec2>,
+    /// Flex direction of the parent node, used when `options` doesn't set a reference direction
+    pub(crate) parent_direction: Option<FlexDirection>,
+    pub(crate) playback: Playback,
+    /// Control if this easing is played or not
+    pub state: EasingState,
+    /// Speed at which this easing is played, `1.` by default. A negative speed plays the easing
+    /// backwards from its current position
+    pub speed: f32,
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for KeyframeEasing<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KeyframeEasing")
+            .field(
+                "keyframes",
+                &self
+                    .keyframes
+                    .iter()
+                    .map(|keyframe| (keyframe.time, &keyframe.value))
+                    .collect::<Vec<_>>(),
+            )
+            .field("state", &self.state)
+            .field("speed", &self.speed)
+            .finish()
+    }
+}
+
+impl_easing_builders!(KeyframeEasing);
+impl_playback_accessors!(KeyframeEasing, easing => easing.playback);
+
+impl<T> KeyframeEasing<T> {
+    /// Create a new track starting at `start`. The duration of the whole track is the one of the
+    /// `EasingType`
+    pub fn new(start: T, easing_type: EasingType) -> Self {
+        let duration = easing_type.duration();
+        KeyframeEasing {
+            keyframes: vec![Keyframe {
+                time: 0.,
+                value: EaseValue(start),
+                ease_function: EaseMethod::Linear,
+            }],
+            duration,
+            options: EasingOptions::default(),
+            parent_size: None,
+            parent_direction: None,
+            playback: Playback::new(duration, easing_type),
+            state: EasingState::Play,
+            speed: 1.,
+        }
+    }
+
+    /// Add a keyframe at `time`, between `0.` (start of the track) and `1.` (end of the track).
+    /// `ease_function` is used to ease from the previous keyframe to this one
+    pub fn keyframe(mut self, time: f32, value: T, ease_function: impl Into<EaseMethod>) -> Self {
+        let time = time.clamp(0., 1.);
+        let index = self
+            .keyframes
+            .iter()
+            .position(|keyframe| keyframe.time > time)
+            .unwrap_or(self.keyframes.len());
+        self.keyframes.insert(
+            index,
+            Keyframe {
+                time,
+                value: EaseValue(value),
+                ease_function: ease_function.into(),
+            },
+        );
+        self
+    }
+
+    /// Add a keyframe at `time` from the start of the track. `ease_function` is used to ease from
+    /// the previous keyframe to this one
+    pub fn keyframe_at(
+        self,
+        time: Duration,
+        value: T,
+        ease_function: impl Into<EaseMethod>,
+    ) -> Self {
+        let time = if self.duration.as_secs_f32() == 0. {
+            1.
+        } else {
+            time.as_secs_f32() / self.duration.as_secs_f32()
+        };
+        self.keyframe(time, value, ease_function)
+    }
+
+    /// Keyframes around `progress` of the track, and the progress and eased factor between them
+    pub(crate) fn sample(&self, progress: f32) -> (&EaseValue<T>, &EaseValue<T>, f32, f32) {
+        match self
+            .keyframes
+            .iter()
+            .position(|keyframe| keyframe.time > progress)
+        {
+            Some(0) => (&self.keyframes[0].value, &self.keyframes[0].value, 0., 0.),
+            Some(next) => {
+                let from = &self.keyframes[next - 1];
+                let to = &self.keyframes[next];
+                let progress = (progress - from.time) / (to.time - from.time);
+                (
+                    &from.value,
+                    &to.value,
+                    progress,
+                    progress.compute(to.ease_function),
+                )
+            }
+            None => {
+                let last = &self.keyframes[self.keyframes.len() - 1];
+                (&last.value, &last.value, 1., 1.)
+            }
+        }
+    }
+}
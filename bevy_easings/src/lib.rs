@@ -12,7 +12,7 @@
 
 //! Ease plugin for Bevy
 
-use std::time::Duration;
+use std::{any::TypeId, time::Duration};
 
 #[cfg(feature = "ease_handle")]
 use rand::Rng;
@@ -23,9 +23,39 @@ use interpolation::Ease as IEase;
 pub use interpolation::EaseFunction;
 pub use interpolation::Lerp;
 
+#[macro_use]
+mod playback;
+use playback::Playback;
 mod plugin;
-pub use plugin::{custom_ease_system, EasingsPlugin};
+pub use plugin::{
+    additive_reset_system, custom_additive_ease_system, custom_clock_system, custom_ease_system,
+    custom_keyframe_ease_system, field_ease_system, lens_ease_system, EasingsPlugin,
+};
+mod additive;
+pub use additive::{Additive, AdditiveEasing, ADDITIVE_EASING_STAGE};
+mod clock;
+mod counter;
+pub use counter::{CounterEasing, CounterFormat};
+mod color;
+pub use color::ColorSpace;
 mod implemented;
+pub use clock::{EasingClock, EasingClocks, EasingTimeSource, GameClock};
+mod field;
+pub use field::{
+    AxisAngle, EaseField, FieldEasing, Lens, LensEasing, SpriteSize, TransformAxisAngle,
+    TransformRotation, TransformScale, TransformTranslation,
+};
+mod keyframes;
+pub use keyframes::KeyframeEasing;
+mod options;
+use options::{ApplyEased, LerpWith};
+pub use options::{DiscreteSwitch, EasingOptions, FieldMask, RotationMode};
+mod spring;
+pub use spring::Spring;
+mod timeline;
+pub use timeline::Timeline;
+mod typewriter;
+pub use typewriter::{TypewriterEasing, TypewriterEvent};
 
 /// Wrapper around a type that can be eased.
 #[derive(Debug)]
@@ -45,6 +75,8 @@ pub enum EasingType {
         duration: Duration,
         /// duration of the pause between two loops
         pause: Option<Duration>,
+        /// number of times the easing is played, forever if `None`
+        times: Option<u32>,
     },
     /// Repeat the animation back and forth
     PingPong {
@@ -52,9 +84,40 @@ pub enum EasingType {
         duration: Duration,
         /// duration of the pause before starting again in the other direction
         pause: Option<Duration>,
+        /// number of trips done before stopping, forever if `None`
+        times: Option<PingPongTimes>,
     },
 }
 
+/// How many trips an `EasingType::PingPong` does before stopping
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum PingPongTimes {
+    /// Count trips in one direction: `HalfTrips(1)` goes from start to end then stops
+    HalfTrips(u32),
+    /// Count trips there and back: `RoundTrips(1)` goes from start to end and back to start then
+    /// stops
+    RoundTrips(u32),
+}
+
+impl PingPongTimes {
+    fn half_trips(self) -> u32 {
+        match self {
+            PingPongTimes::HalfTrips(times) => times,
+            PingPongTimes::RoundTrips(times) => times * 2,
+        }
+    }
+}
+
+impl EasingType {
+    fn duration(&self) -> Duration {
+        match *self {
+            EasingType::Once { duration } => duration,
+            EasingType::Loop { duration, .. } => duration,
+            EasingType::PingPong { duration, .. } => duration,
+        }
+    }
+}
+
 /// Control if an easing is played
 #[derive(PartialEq, Debug, Clone, Copy)]
 pub enum EasingState {
@@ -75,6 +138,78 @@ impl std::ops::Not for EasingState {
     }
 }
 
+/// Event sent when an easing reaches a milestone
+#[derive(Debug, Clone, Copy)]
+pub struct EasingEvent {
+    /// Entity on which the easing is running
+    pub entity: Entity,
+    /// `TypeId` of the eased component
+    pub component: TypeId,
+    /// What happened to the easing
+    pub kind: EasingEventKind,
+    /// Number of iterations completed by the easing. For `EasingType::PingPong`, each trip in one
+    /// direction is an iteration
+    pub iteration: u32,
+    /// Direction the easing is playing in after this event
+    pub direction: EasingDirection,
+}
+
+impl EasingEvent {
+    fn new<T: 'static>(entity: Entity, kind: EasingEventKind) -> Self {
+        EasingEvent {
+            entity,
+            component: TypeId::of::<T>(),
+            kind,
+            iteration: 0,
+            direction: EasingDirection::Forward,
+        }
+    }
+
+    /// Check if this event is about an easing on the component `T`
+    pub fn is<T: 'static>(&self) -> bool {
+        self.component == TypeId::of::<T>()
+    }
+}
+
+/// Kind of an `EasingEvent`
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum EasingEventKind {
+    /// An easing of type `EasingType::Once` completed, it has been removed from the entity
+    Finished,
+    /// An easing that is part of a chain completed, the next one will start
+    ChainSegmentFinished,
+    /// All easings of a chain have completed, it has been removed from the entity
+    ChainFinished,
+    /// An easing of type `EasingType::Loop` restarted from the start
+    LoopRestarted,
+    /// An easing of type `EasingType::PingPong` reached one end and will now play in the other
+    /// direction
+    DirectionReversed,
+    /// A pause between two iterations started
+    PauseStarted,
+    /// A pause between two iterations ended
+    PauseEnded,
+}
+
+/// Direction in which an easing is playing
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum EasingDirection {
+    /// From the start value to the end value
+    Forward,
+    /// From the end value to the start value
+    Backward,
+}
+
+impl EasingDirection {
+    fn from_sign(direction: i16) -> Self {
+        if direction.is_positive() {
+            EasingDirection::Forward
+        } else {
+            EasingDirection::Backward
+        }
+    }
+}
+
 /// Describe how eased value should be computed
 #[derive(Clone, Copy)]
 pub enum EaseMethod {
@@ -86,6 +221,42 @@ pub enum EaseMethod {
     Discrete,
     /// Use a custom function to interpolate the value
     CustomFunction(fn(f32) -> f32),
+    /// Follow a cubic Bézier curve defined by its two control points `(x1, y1, x2, y2)`, like
+    /// `cubic-bezier(x1, y1, x2, y2)` in CSS. `x1` and `x2` are clamped to `[0, 1]`
+    CubicBezier(f32, f32, f32, f32),
+    /// Move in discrete steps, like `steps(n, jump)` in CSS
+    Steps(u32, StepJump),
+    /// Follow a damped spring. The duration of the easing is the time the spring takes to settle,
+    /// the one from `EasingType` is ignored
+    Spring(Spring),
+}
+
+/// Where the jumps happen in `EaseMethod::Steps`
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum StepJump {
+    /// First jump happens as soon as the easing starts, like CSS `jump-start`
+    Start,
+    /// Last jump happens when the easing ends, like CSS `jump-end`
+    End,
+    /// Jump both when the easing starts and when it ends, like CSS `jump-both`
+    Both,
+    /// Don't jump when the easing starts nor when it ends, like CSS `jump-none`
+    None,
+}
+
+impl EaseMethod {
+    fn duration(&self, easing_type: EasingType) -> Duration {
+        match self {
+            EaseMethod::Spring(spring) => spring.settle_duration(),
+            _ => easing_type.duration(),
+        }
+    }
+}
+
+impl From<Spring> for EaseMethod {
+    fn from(spring: Spring) -> Self {
+        EaseMethod::Spring(spring)
+    }
 }
 
 impl Into<EaseMethod> for EaseFunction {
@@ -119,23 +290,90 @@ impl MyEaser for f32 {
                 }
             }
             EaseMethod::CustomFunction(function) => function(self),
+            EaseMethod::CubicBezier(x1, y1, x2, y2) => cubic_bezier(x1, y1, x2, y2, self),
+            EaseMethod::Spring(spring) => spring.position(self),
+            EaseMethod::Steps(steps, jump) => {
+                let steps = steps.max(1);
+                let mut current = (self * steps as f32).floor() as u32;
+                if jump == StepJump::Start || jump == StepJump::Both {
+                    current += 1;
+                }
+                let jumps = match jump {
+                    StepJump::None => (steps - 1).max(1),
+                    StepJump::Both => steps + 1,
+                    StepJump::Start | StepJump::End => steps,
+                };
+                current.min(jumps) as f32 / jumps as f32
+            }
         }
     }
 }
 
+/// Solve the cubic Bézier for `x` then sample it for `y`, the same way browsers do: Newton's
+/// method first, falling back to bisection when it doesn't converge
+fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32, x: f32) -> f32 {
+    let x1 = x1.clamp(0., 1.);
+    let x2 = x2.clamp(0., 1.);
+    let cx = 3. * x1;
+    let bx = 3. * (x2 - x1) - cx;
+    let ax = 1. - cx - bx;
+    let cy = 3. * y1;
+    let by = 3. * (y2 - y1) - cy;
+    let ay = 1. - cy - by;
+    let sample_x = |t: f32| ((ax * t + bx) * t + cx) * t;
+    let epsilon = 1e-6;
+
+    let mut t = x;
+    let mut solved = false;
+    for _ in 0..8 {
+        let error = sample_x(t) - x;
+        if error.abs() < epsilon {
+            solved = true;
+            break;
+        }
+        let derivative = (3. * ax * t + 2. * bx) * t + cx;
+        if derivative.abs() < 1e-6 {
+            break;
+        }
+        t -= error / derivative;
+    }
+    if !solved {
+        let (mut low, mut high) = (0., 1.);
+        t = x;
+        for _ in 0..32 {
+            let current = sample_x(t);
+            if (current - x).abs() < epsilon {
+                break;
+            }
+            if x > current {
+                low = t;
+            } else {
+                high = t;
+            }
+            t = (low + high) / 2.;
+        }
+    }
+    ((ay * t + by) * t + cy) * t
+}
+
 /// Component to control an easing
 pub struct EasingComponent<T> {
     start: Option<EaseValue<T>>,
     end: EaseValue<T>,
     ease_function: EaseMethod,
-    timer: Timer,
+    options: EasingOptions,
+    /// Size of the parent node, used when `options` doesn't set a reference size
+    parent_size: Option<Vec2>,
+    /// Flex direction of the parent node, used when `options` doesn't set a reference direction
+    parent_direction: Option<FlexDirection>,
+    playback: Playback,
     /// Control if this easing is played or not
     pub state: EasingState,
-    paused: bool,
-    easing_type: EasingType,
+    /// Speed at which this easing is played, `1.` by default. A negative speed plays the easing
+    /// backwards from its current position
+    pub speed: f32,
     #[cfg(feature = "ease_handle")]
     id: i128,
-    direction: i16,
 }
 
 impl<T: std::fmt::Debug> std::fmt::Debug for EasingComponent<T> {
@@ -144,44 +382,101 @@ impl<T: std::fmt::Debug> std::fmt::Debug for EasingComponent<T> {
             .field("start", &self.start)
             .field("end", &self.end)
             .field("state", &self.state)
+            .field("speed", &self.speed)
             .finish()
     }
 }
 
-impl<T: Default> EasingComponent<T> {
-    /// Start a chain of easing, adding a new one after the first one
-    pub fn ease_to(
-        self,
+impl<T> EasingComponent<T> {
+    fn new(
+        start: Option<T>,
         end: T,
         ease_function: impl Into<EaseMethod>,
         easing_type: EasingType,
-    ) -> EasingChainComponent<T> {
+    ) -> Self {
         #[cfg(feature = "ease_handle")]
         let mut rng = rand::thread_rng();
+        let ease_function: EaseMethod = ease_function.into();
 
-        let next = EasingComponent {
-            start: None,
+        EasingComponent {
+            start: start.map(EaseValue),
             end: EaseValue(end),
-            ease_function: ease_function.into(),
-            timer: match easing_type {
-                EasingType::Once { duration } => Timer::new(duration, false),
-                EasingType::Loop { duration, .. } => Timer::new(duration, false),
-                EasingType::PingPong { duration, .. } => Timer::new(duration, false),
-            },
+            ease_function,
+            options: EasingOptions::default(),
+            parent_size: None,
+            parent_direction: None,
+            playback: Playback::new(ease_function.duration(easing_type), easing_type),
             state: EasingState::Play,
-            paused: false,
-            easing_type,
+            speed: 1.,
             #[cfg(feature = "ease_handle")]
             id: rng.gen(),
-            direction: 1,
-        };
+        }
+    }
+}
 
-        EasingChainComponent(vec![next, self])
+impl_easing_builders!(EasingComponent);
+impl_playback_accessors!(EasingComponent, easing => easing.playback);
+
+impl<T: Default> EasingComponent<T> {
+    /// Start a chain of easing, adding a new one after the first one
+    pub fn ease_to(
+        self,
+        end: T,
+        ease_function: impl Into<EaseMethod>,
+        easing_type: EasingType,
+    ) -> EasingChainComponent<T> {
+        let clock = self.playback.clock;
+        let mut next = EasingComponent::new(None, end, ease_function, easing_type);
+        next.playback.clock = clock;
+
+        EasingChainComponent {
+            easings: vec![self, next],
+            current: 0,
+            repeat: ChainRepeat::Once,
+            direction: 1,
+            iteration: 0,
+            clock,
+            state: EasingState::Play,
+            speed: 1.,
+        }
     }
 }
 
-/// Component to control a chain of easing
-pub struct EasingChainComponent<T>(Vec<EasingComponent<T>>);
+/// How should a chain of easing repeat
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum ChainRepeat {
+    /// Play the chain once
+    Once,
+    /// Restart the chain from its first easing once finished
+    Loop {
+        /// number of times the chain is played, forever if `None`
+        times: Option<u32>,
+    },
+    /// Play the chain back and forth, playing its easings backward and in reverse order
+    PingPong {
+        /// number of trips done before stopping, forever if `None`
+        times: Option<PingPongTimes>,
+    },
+}
+
+/// Component to control a chain of easing. Reading and seeking its position applies to the easing
+/// currently playing
+pub struct EasingChainComponent<T> {
+    easings: Vec<EasingComponent<T>>,
+    current: usize,
+    repeat: ChainRepeat,
+    direction: i16,
+    iteration: u32,
+    /// Clock of all easings of this chain, including the ones added later
+    clock: Option<EasingClock>,
+    /// Control if this chain is played or not
+    pub state: EasingState,
+    /// Speed at which this chain is played, `1.` by default. A negative speed plays the chain
+    /// backwards from its current position, going through its easings in reverse order
+    pub speed: f32,
+}
+
+impl_playback_accessors!(EasingChainComponent, chain => chain.easings[chain.current].playback);
 
 impl<T: Default> EasingChainComponent<T> {
     /// Add a new easing at the end of the current chain
@@ -191,27 +486,46 @@ impl<T: Default> EasingChainComponent<T> {
         ease_function: impl Into<EaseMethod>,
         easing_type: EasingType,
     ) -> EasingChainComponent<T> {
-        #[cfg(feature = "ease_handle")]
-        let mut rng = rand::thread_rng();
+        let mut next = EasingComponent::new(None, end, ease_function, easing_type);
+        next.playback.clock = self.clock;
 
-        let next = EasingComponent {
-            start: None,
-            end: EaseValue(end),
-            ease_function: ease_function.into(),
-            timer: match easing_type {
-                EasingType::Once { duration } => Timer::new(duration, false),
-                EasingType::Loop { duration, .. } => Timer::new(duration, false),
-                EasingType::PingPong { duration, .. } => Timer::new(duration, false),
-            },
-            state: EasingState::Play,
-            paused: false,
-            easing_type,
-            #[cfg(feature = "ease_handle")]
-            id: rng.gen(),
-            direction: 1,
-        };
+        self.easings.push(next);
+        self
+    }
+}
 
-        self.0.insert(0, next);
+impl<T> EasingChainComponent<T> {
+    /// Set how this chain repeats once all its easings are done. The easings are kept and played
+    /// again
+    pub fn with_repeat(mut self, repeat: ChainRepeat) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    /// Wait for `delay` before starting the last easing added to this chain. The component keeps
+    /// the value it had at the end of the previous easing until then
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        if let Some(easing) = self.easings.last_mut() {
+            easing.playback.set_delay(delay);
+        }
+        self
+    }
+
+    /// Interpolate the values of the last easing added to this chain following `options`
+    pub fn with_options(mut self, options: EasingOptions) -> Self {
+        if let Some(easing) = self.easings.last_mut() {
+            easing.options = options;
+        }
+        self
+    }
+
+    /// Advance all easings of this chain with `clock` instead of the default one, including the
+    /// ones added after
+    pub fn with_clock(mut self, clock: EasingClock) -> Self {
+        self.clock = Some(clock);
+        for easing in self.easings.iter_mut() {
+            easing.playback.clock = Some(clock);
+        }
         self
     }
 }
@@ -225,25 +539,7 @@ pub trait Ease: Sized {
         ease_function: impl Into<EaseMethod>,
         easing_type: EasingType,
     ) -> EasingComponent<Self> {
-        #[cfg(feature = "ease_handle")]
-        let mut rng = rand::thread_rng();
-
-        EasingComponent {
-            start: start.map(EaseValue),
-            end: EaseValue(end),
-            ease_function: ease_function.into(),
-            timer: match easing_type {
-                EasingType::Once { duration } => Timer::new(duration, false),
-                EasingType::Loop { duration, .. } => Timer::new(duration, false),
-                EasingType::PingPong { duration, .. } => Timer::new(duration, false),
-            },
-            state: EasingState::Play,
-            paused: false,
-            easing_type,
-            #[cfg(feature = "ease_handle")]
-            id: rng.gen(),
-            direction: 1,
-        }
+        EasingComponent::new(start, end, ease_function, easing_type)
     }
 
     /// Create a new easing with the current component value as a starting point
@@ -271,7 +567,12 @@ where
 }
 
 trait IntermediateLerp: Sized {
-    fn lerp(start: &EaseValue<&Self>, end: &EaseValue<&Self>, scalar: &f32) -> Self;
+    fn lerp(
+        start: &EaseValue<&Self>,
+        end: &EaseValue<&Self>,
+        scalar: &f32,
+        options: &EasingOptions,
+    ) -> Self;
 }
 
 /// Trait to mark custom component that can be eased. It will be automatically implemented if the custom component implement `Lerp`
@@ -283,25 +584,7 @@ pub trait CustomComponentEase: Sized {
         ease_function: impl Into<EaseMethod>,
         easing_type: EasingType,
     ) -> EasingComponent<Self> {
-        #[cfg(feature = "ease_handle")]
-        let mut rng = rand::thread_rng();
-
-        EasingComponent {
-            start: start.map(EaseValue),
-            end: EaseValue(end),
-            ease_function: ease_function.into(),
-            timer: match easing_type {
-                EasingType::Once { duration } => Timer::new(duration, false),
-                EasingType::Loop { duration, .. } => Timer::new(duration, false),
-                EasingType::PingPong { duration, .. } => Timer::new(duration, false),
-            },
-            state: EasingState::Play,
-            paused: false,
-            easing_type,
-            #[cfg(feature = "ease_handle")]
-            id: rng.gen(),
-            direction: 1,
-        }
+        EasingComponent::new(start, end, ease_function, easing_type)
     }
 
     /// Create a new easing with the current component value as a starting point
@@ -316,3 +599,57 @@ pub trait CustomComponentEase: Sized {
 }
 
 impl<T> CustomComponentEase for T where T: Lerp<Scalar = f32> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 1e-4,
+            "expected {}, got {}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn cubic_bezier_ease() {
+        assert_close(cubic_bezier(0.25, 0.1, 0.25, 1., 0.), 0.);
+        assert_close(cubic_bezier(0.25, 0.1, 0.25, 1., 0.25), 0.408_510_6);
+        assert_close(cubic_bezier(0.25, 0.1, 0.25, 1., 0.5), 0.802_403_4);
+        assert_close(cubic_bezier(0.25, 0.1, 0.25, 1., 1.), 1.);
+    }
+
+    #[test]
+    fn cubic_bezier_ease_in_out() {
+        assert_close(cubic_bezier(0.42, 0., 0.58, 1., 0.25), 0.129_161_9);
+        assert_close(cubic_bezier(0.42, 0., 0.58, 1., 0.5), 0.5);
+        assert_close(cubic_bezier(0.42, 0., 0.58, 1., 0.75), 0.870_838);
+    }
+
+    #[test]
+    fn cubic_bezier_overshoot() {
+        assert!(cubic_bezier(0.68, -0.55, 0.265, 1.55, 0.2) < 0.);
+        assert!(cubic_bezier(0.68, -0.55, 0.265, 1.55, 0.8) > 1.);
+        assert_close(cubic_bezier(0.68, -0.55, 0.265, 1.55, 0.), 0.);
+        assert_close(cubic_bezier(0.68, -0.55, 0.265, 1.55, 1.), 1.);
+    }
+
+    #[test]
+    fn steps_jumps() {
+        let steps = |jump, progress: f32| progress.compute(EaseMethod::Steps(4, jump));
+        assert_close(steps(StepJump::End, 0.), 0.);
+        assert_close(steps(StepJump::End, 0.3), 0.25);
+        assert_close(steps(StepJump::End, 1.), 1.);
+        assert_close(steps(StepJump::Start, 0.), 0.25);
+        assert_close(steps(StepJump::Start, 0.3), 0.5);
+        assert_close(steps(StepJump::Start, 1.), 1.);
+        assert_close(steps(StepJump::Both, 0.), 0.2);
+        assert_close(steps(StepJump::Both, 0.3), 0.4);
+        assert_close(steps(StepJump::Both, 1.), 1.);
+        assert_close(steps(StepJump::None, 0.), 0.);
+        assert_close(steps(StepJump::None, 0.3), 1. / 3.);
+        assert_close(steps(StepJump::None, 1.), 1.);
+    }
+}
@@ -0,0 +1,150 @@
+use bevy::{math::Vec2, ui::FlexDirection};
+
+use crate::ColorSpace;
+
+/// Options changing how an easing interpolates between its values
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EasingOptions {
+    /// How rotations are interpolated
+    pub rotation: RotationMode,
+    /// Color space in which colors are interpolated
+    pub color_space: ColorSpace,
+    /// Size against which `Val`s in different units are resolved when easing a `Style`. When not
+    /// set, the size of the parent node is used, or the size of the window for root nodes
+    pub reference_size: Option<Vec2>,
+    /// Flex direction of the container, giving the axis against which percentages of `flex_basis`
+    /// are resolved when easing a `Style`. When not set, the direction of the parent node is used,
+    /// or `FlexDirection::Row` for root nodes
+    pub reference_direction: Option<FlexDirection>,
+    /// When values that can't be interpolated, like enums or `Val::Auto`, change from start to end
+    pub switch: DiscreteSwitch,
+    /// Fields of `Sprite`, `Transform` and `Style` written by the easing. The other fields of the
+    /// component are left untouched
+    pub fields: FieldMask,
+}
+
+impl Default for EasingOptions {
+    fn default() -> Self {
+        EasingOptions {
+            rotation: RotationMode::default(),
+            color_space: ColorSpace::default(),
+            reference_size: None,
+            reference_direction: None,
+            switch: DiscreteSwitch::default(),
+            fields: FieldMask::ALL,
+        }
+    }
+}
+
+/// Set of fields written by an easing. Masks can be combined with `|`, like
+/// `FieldMask::TRANSLATION | FieldMask::SCALE`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FieldMask(u32);
+
+impl FieldMask {
+    /// All fields
+    pub const ALL: FieldMask = FieldMask(u32::MAX);
+    /// `Transform::translation`
+    pub const TRANSLATION: FieldMask = FieldMask(1);
+    /// `Transform::rotation`
+    pub const ROTATION: FieldMask = FieldMask(1 << 1);
+    /// `Transform::scale`
+    pub const SCALE: FieldMask = FieldMask(1 << 2);
+    /// `Sprite::size` and `Style::size`
+    pub const SIZE: FieldMask = FieldMask(1 << 3);
+    /// `Style::min_size`
+    pub const MIN_SIZE: FieldMask = FieldMask(1 << 4);
+    /// `Style::max_size`
+    pub const MAX_SIZE: FieldMask = FieldMask(1 << 5);
+    /// `Style::position`
+    pub const POSITION: FieldMask = FieldMask(1 << 6);
+    /// `Style::margin`
+    pub const MARGIN: FieldMask = FieldMask(1 << 7);
+    /// `Style::padding`
+    pub const PADDING: FieldMask = FieldMask(1 << 8);
+    /// `Style::border`
+    pub const BORDER: FieldMask = FieldMask(1 << 9);
+    /// `Style::flex_grow`, `Style::flex_shrink` and `Style::flex_basis`
+    pub const FLEX: FieldMask = FieldMask(1 << 10);
+    /// `Style::aspect_ratio`
+    pub const ASPECT_RATIO: FieldMask = FieldMask(1 << 11);
+    /// Fields of `Style` that can't be interpolated, like `display`, `flex_direction` or
+    /// `align_items`
+    pub const LAYOUT: FieldMask = FieldMask(1 << 12);
+
+    /// Check if all fields of `other` are in this mask
+    pub fn contains(self, other: FieldMask) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl std::ops::BitOr for FieldMask {
+    type Output = FieldMask;
+
+    fn bitor(self, other: FieldMask) -> FieldMask {
+        FieldMask(self.0 | other.0)
+    }
+}
+
+/// How to interpolate between two rotations
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RotationMode {
+    /// Spherical interpolation along the shortest path, at constant angular speed
+    Shortest,
+    /// Normalized linear interpolation along the shortest path. Cheaper than `Shortest`, but the
+    /// angular speed is not constant
+    Nlerp,
+    /// Spherical interpolation along the longest path, going the other way around
+    Longest,
+}
+
+impl Default for RotationMode {
+    fn default() -> Self {
+        RotationMode::Shortest
+    }
+}
+
+/// When a value that can't be interpolated changes from its start to its end value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiscreteSwitch {
+    /// As soon as the easing starts
+    Start,
+    /// At half time
+    Midpoint,
+    /// Once the easing reaches its end
+    End,
+}
+
+impl Default for DiscreteSwitch {
+    fn default() -> Self {
+        DiscreteSwitch::Midpoint
+    }
+}
+
+impl DiscreteSwitch {
+    /// Check if values should have switched to their end at `progress` of the easing, the linear
+    /// progress before applying the ease function, so that overshooting easings don't switch back
+    /// and forth
+    pub(crate) fn switched(self, progress: f32) -> bool {
+        match self {
+            DiscreteSwitch::Start => progress > 0.,
+            DiscreteSwitch::Midpoint => progress >= 0.5,
+            DiscreteSwitch::End => progress >= 1.,
+        }
+    }
+}
+
+/// Trait for values that can be interpolated following `EasingOptions`
+pub trait LerpWith: Sized {
+    /// Interpolate between `self` and `other`. `scalar` is the eased factor, and `progress` the
+    /// linear progress of the easing, at which values that can't be interpolated switch
+    fn lerp_with(&self, other: &Self, scalar: &f32, progress: f32, options: &EasingOptions)
+        -> Self;
+}
+
+/// Trait for components that can receive an eased value. Only the fields that are eased are
+/// written, so that other fields can still be changed while the easing runs
+pub trait ApplyEased: Sized {
+    /// Write `value` to this component
+    fn apply_eased(&mut self, value: Self, options: &EasingOptions);
+}
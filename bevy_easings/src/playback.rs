@@ -0,0 +1,633 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{
+    EasingClock, EasingClocks, EasingDirection, EasingEvent, EasingEventKind, EasingState,
+    EasingType,
+};
+
+/// Implement the builders shared by easings with their own `Playback` and `EasingOptions`
+macro_rules! impl_easing_builders {
+    ($easing:ident) => {
+        impl<T> $easing<T> {
+            /// Advance this easing with `clock` instead of the default one
+            pub fn with_clock(mut self, clock: $crate::EasingClock) -> Self {
+                self.playback.clock = Some(clock);
+                self
+            }
+
+            /// Drive this easing with `timeline`, starting `offset` after the start of the
+            /// timeline. The easing then follows the timeline instead of its own clock, and stays
+            /// on the entity once finished so that the timeline can be sought back
+            pub fn in_timeline(
+                mut self,
+                timeline: ::bevy::prelude::Entity,
+                offset: ::std::time::Duration,
+            ) -> Self {
+                self.playback.timeline = Some((timeline, offset));
+                self
+            }
+
+            /// Wait for `delay` before starting this easing. The component keeps its start value
+            /// until then
+            pub fn with_delay(mut self, delay: ::std::time::Duration) -> Self {
+                self.playback.set_delay(delay);
+                self
+            }
+
+            /// Interpolate the values of this easing following `options`
+            pub fn with_options(mut self, options: $crate::EasingOptions) -> Self {
+                self.options = options;
+                self
+            }
+
+            /// Options to interpolate with, completed with the size and direction of the parent
+            /// node
+            pub(crate) fn lerp_options(&self) -> $crate::EasingOptions {
+                $crate::EasingOptions {
+                    reference_size: self.options.reference_size.or(self.parent_size),
+                    reference_direction: self.options.reference_direction.or(self.parent_direction),
+                    ..self.options
+                }
+            }
+        }
+    };
+}
+
+/// Implement the accessors reading and seeking the `Playback` found at `$playback`
+macro_rules! impl_playback_accessors {
+    ($easing:ident, $this:ident => $playback:expr) => {
+        impl<T> $easing<T> {
+            /// Check if this easing is still waiting for its delay before starting
+            pub fn is_pending(&self) -> bool {
+                let $this = self;
+                $playback.is_pending()
+            }
+
+            /// Progress through the current iteration, between `0.` and `1.`
+            pub fn progress(&self) -> f32 {
+                let $this = self;
+                $playback.progress()
+            }
+
+            /// Time elapsed in the current iteration
+            pub fn elapsed(&self) -> ::std::time::Duration {
+                let $this = self;
+                $playback.elapsed()
+            }
+
+            /// Direction in which the current iteration is playing
+            pub fn direction(&self) -> $crate::EasingDirection {
+                let $this = self;
+                $playback.direction()
+            }
+
+            /// Number of iterations completed. For `EasingType::PingPong`, each trip in one
+            /// direction is an iteration
+            pub fn iteration(&self) -> u32 {
+                let $this = self;
+                $playback.iteration()
+            }
+
+            /// Jump to `progress` of the current iteration, between `0.` and `1.`. The component
+            /// will be updated on the next run of the easing system
+            pub fn set_progress(&mut self, progress: f32) {
+                let $this = self;
+                $playback.set_progress(progress);
+            }
+
+            /// Jump to `elapsed` in the current iteration. The component will be updated on the
+            /// next run of the easing system
+            pub fn set_elapsed(&mut self, elapsed: ::std::time::Duration) {
+                let $this = self;
+                $playback.set_elapsed(elapsed);
+            }
+        }
+    };
+}
+
+/// Timing of an easing: how far it is, in which direction, and if it's pausing between two
+/// iterations
+pub(crate) struct Playback {
+    timer: Timer,
+    duration: Duration,
+    paused: bool,
+    easing_type: EasingType,
+    pub(crate) direction: i16,
+    iteration: u32,
+    pub(crate) clock: Option<EasingClock>,
+    /// Time left before the easing starts
+    delay: Duration,
+    initial_delay: Duration,
+    /// Timeline driving this easing, and when the easing starts in it
+    pub(crate) timeline: Option<(Entity, Duration)>,
+}
+
+/// Result of advancing a `Playback` by one frame
+pub(crate) struct PlaybackStep {
+    /// Progress of the easing in its current direction, if the component should be updated this
+    /// frame
+    pub(crate) progress: Option<f32>,
+    /// The easing is done and should be removed
+    pub(crate) finished: bool,
+}
+
+impl Playback {
+    pub(crate) fn new(duration: Duration, easing_type: EasingType) -> Self {
+        Playback {
+            timer: Timer::new(duration, false),
+            duration,
+            paused: false,
+            easing_type,
+            direction: 1,
+            iteration: 0,
+            clock: None,
+            delay: Duration::default(),
+            initial_delay: Duration::default(),
+            timeline: None,
+        }
+    }
+
+    pub(crate) fn direction(&self) -> EasingDirection {
+        EasingDirection::from_sign(self.direction)
+    }
+
+    pub(crate) fn iteration(&self) -> u32 {
+        self.iteration
+    }
+
+    pub(crate) fn elapsed(&self) -> Duration {
+        if self.paused {
+            self.duration
+        } else {
+            self.timer.elapsed()
+        }
+    }
+
+    pub(crate) fn progress(&self) -> f32 {
+        if self.paused || self.duration.as_secs_f32() == 0. {
+            1.
+        } else {
+            self.timer.percent()
+        }
+    }
+
+    pub(crate) fn set_delay(&mut self, delay: Duration) {
+        self.delay = delay;
+        self.initial_delay = delay;
+    }
+
+    /// Bring the easing back to its initial state, ready to play in `direction`
+    pub(crate) fn rewind(&mut self, direction: i16) {
+        self.timer.set_duration(self.duration);
+        self.timer.reset();
+        self.paused = false;
+        self.iteration = 0;
+        self.direction = direction;
+        self.delay = self.initial_delay;
+    }
+
+    pub(crate) fn is_pending(&self) -> bool {
+        self.delay.as_secs_f32() != 0.
+    }
+
+    pub(crate) fn set_elapsed(&mut self, elapsed: Duration) {
+        self.delay = Duration::default();
+        if self.paused {
+            self.timer.set_duration(self.duration);
+            self.paused = false;
+        }
+        self.timer.reset();
+        self.timer.set_elapsed(elapsed.min(self.duration));
+    }
+
+    pub(crate) fn set_progress(&mut self, progress: f32) {
+        self.set_elapsed(self.duration.mul_f32(progress.clamp(0., 1.)));
+    }
+
+    pub(crate) fn event<T: 'static>(&self, entity: Entity, kind: EasingEventKind) -> EasingEvent {
+        EasingEvent {
+            iteration: self.iteration,
+            direction: self.direction(),
+            ..EasingEvent::new::<T>(entity, kind)
+        }
+    }
+
+    pub(crate) fn step<T: 'static>(
+        &mut self,
+        state: EasingState,
+        speed: f32,
+        entity: Entity,
+        clocks: &EasingClocks,
+        events: &mut EventWriter<EasingEvent>,
+    ) -> PlaybackStep {
+        let mut step = PlaybackStep {
+            progress: None,
+            finished: false,
+        };
+
+        if let Some((timeline, offset)) = self.timeline {
+            step.progress = clocks
+                .timeline(timeline)
+                .and_then(|elapsed| self.follow_timeline(elapsed.checked_sub(offset)));
+            return step;
+        }
+
+        let reversed = speed < 0.;
+        let mut rewound = false;
+        if self.is_pending() {
+            if state == EasingState::Play {
+                let delta = clocks.delta(self.clock).mul_f32(speed.abs());
+                self.delay = self.delay.checked_sub(delta).unwrap_or_default();
+            }
+            if self.duration.as_secs_f32() != 0. {
+                step.progress = Some(if self.direction.is_positive() { 0. } else { 1. });
+            }
+            return step;
+        }
+        if state == EasingState::Play {
+            let delta = clocks.delta(self.clock).mul_f32(speed.abs());
+            if reversed && !self.paused {
+                let elapsed = self.timer.elapsed().checked_sub(delta).unwrap_or_default();
+                self.timer.set_elapsed(elapsed);
+                rewound = elapsed.as_secs_f32() == 0.;
+            } else {
+                self.timer.tick(delta);
+            }
+        }
+        if self.paused {
+            if self.timer.just_finished() {
+                self.timer.set_duration(self.duration);
+                self.restart(reversed);
+                self.paused = false;
+                events.send(self.event::<T>(entity, EasingEventKind::PauseEnded));
+                if let EasingType::Loop { .. } = self.easing_type {
+                    events.send(self.event::<T>(entity, EasingEventKind::LoopRestarted));
+                }
+            }
+        } else {
+            if self.timer.duration().as_secs_f32() != 0. {
+                step.progress = Some(if self.direction.is_positive() {
+                    self.timer.percent()
+                } else {
+                    self.timer.percent_left()
+                });
+            }
+            if (!reversed && self.timer.finished()) || rewound {
+                match self.easing_type {
+                    EasingType::Once { .. } => {
+                        step.finished = true;
+                    }
+                    EasingType::Loop { pause, times, .. } => {
+                        if self.next_iteration(reversed, times) {
+                            step.finished = true;
+                        } else if let Some(pause) = pause {
+                            self.timer.set_duration(pause);
+                            self.timer.reset();
+                            self.paused = true;
+                            events.send(self.event::<T>(entity, EasingEventKind::PauseStarted));
+                        } else {
+                            self.restart(reversed);
+                            events.send(self.event::<T>(entity, EasingEventKind::LoopRestarted));
+                        }
+                    }
+                    EasingType::PingPong { pause, times, .. } => {
+                        if self.next_iteration(reversed, times.map(|times| times.half_trips())) {
+                            step.finished = true;
+                        } else {
+                            self.direction *= -1;
+                            events
+                                .send(self.event::<T>(entity, EasingEventKind::DirectionReversed));
+                            if let Some(pause) = pause {
+                                self.timer.set_duration(pause);
+                                self.timer.reset();
+                                self.paused = true;
+                                events.send(self.event::<T>(entity, EasingEventKind::PauseStarted));
+                            } else {
+                                self.restart(reversed);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        step
+    }
+
+    /// Move to the next iteration, or back to the previous one when playing backwards, with `max`
+    /// the number of iterations. Returns `true` when there is none left
+    fn next_iteration(&mut self, reversed: bool, max: Option<u32>) -> bool {
+        if reversed {
+            if self.iteration == 0 {
+                return true;
+            }
+            self.iteration -= 1;
+            false
+        } else {
+            self.iteration += 1;
+            max.map_or(false, |max| self.iteration >= max)
+        }
+    }
+
+    /// Set the state of the easing `time` after it started in its timeline, and return its
+    /// progress. Easings in a timeline are never finished, so that seeking back works
+    fn follow_timeline(&mut self, time: Option<Duration>) -> Option<f32> {
+        let time = time.unwrap_or_default();
+        self.paused = false;
+        self.direction = 1;
+        self.iteration = 0;
+        self.timer.set_duration(self.duration);
+        self.timer.reset();
+        if let Some(time) = time.checked_sub(self.initial_delay) {
+            self.delay = Duration::default();
+            if self.duration.as_secs_f32() == 0. {
+                return None;
+            }
+
+            let duration = self.duration.as_secs_f32();
+            let time = time.as_secs_f32();
+            let (pause, half_trips, ping_pong) = match self.easing_type {
+                EasingType::Once { .. } => (None, Some(1), false),
+                EasingType::Loop { pause, times, .. } => (pause, times, false),
+                EasingType::PingPong { pause, times, .. } => {
+                    (pause, times.map(|times| times.half_trips()), true)
+                }
+            };
+            let period = duration + pause.unwrap_or_default().as_secs_f32();
+            let iteration = (time / period).floor() as u32;
+            let (iteration, local) = match half_trips {
+                Some(max) if iteration >= max => (max.max(1) - 1, duration),
+                _ => (
+                    iteration,
+                    (time - iteration as f32 * period).max(0.).min(duration),
+                ),
+            };
+            self.iteration = iteration;
+            if ping_pong && iteration % 2 == 1 {
+                self.direction = -1;
+            }
+            self.timer.set_elapsed(Duration::from_secs_f32(local));
+            Some(if self.direction.is_positive() {
+                self.timer.percent()
+            } else {
+                self.timer.percent_left()
+            })
+        } else {
+            self.delay = self.initial_delay - time;
+            if self.duration.as_secs_f32() == 0. {
+                None
+            } else {
+                Some(0.)
+            }
+        }
+    }
+
+    /// Restart the timer from the start, or from the end when playing backwards
+    fn restart(&mut self, reversed: bool) {
+        self.timer.reset();
+        if reversed {
+            self.timer.set_elapsed(self.duration);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::app::ManualEventReader;
+
+    use super::*;
+    use crate::PingPongTimes;
+
+    struct Frame {
+        playback: Playback,
+        finished: bool,
+    }
+
+    fn step_system(
+        mut frame: ResMut<Frame>,
+        clocks: Res<EasingClocks>,
+        mut events: EventWriter<EasingEvent>,
+    ) {
+        let step =
+            frame
+                .playback
+                .step::<()>(EasingState::Play, 1., Entity::new(0), &clocks, &mut events);
+        frame.finished = step.finished;
+    }
+
+    /// Runs a `Playback` frame by frame, and collects the events it sends
+    struct Harness {
+        world: World,
+        stage: SystemStage,
+        reader: ManualEventReader<EasingEvent>,
+    }
+
+    impl Harness {
+        fn new(easing_type: EasingType) -> Self {
+            let mut world = World::default();
+            world.insert_resource(Frame {
+                playback: Playback::new(Duration::from_secs(1), easing_type),
+                finished: false,
+            });
+            world.insert_resource(EasingClocks::default());
+            let events = Events::<EasingEvent>::default();
+            let reader = events.get_reader();
+            world.insert_resource(events);
+            Harness {
+                world,
+                stage: SystemStage::single(step_system.system()),
+                reader,
+            }
+        }
+
+        /// Advance by `millis`, returning the kinds of events sent and if the easing finished
+        fn advance(&mut self, millis: u64) -> (Vec<EasingEventKind>, bool) {
+            self.world
+                .get_resource_mut::<EasingClocks>()
+                .unwrap()
+                .set_delta(EasingClock::Real, Duration::from_millis(millis));
+            self.stage.run(&mut self.world);
+            let events = self.world.get_resource::<Events<EasingEvent>>().unwrap();
+            let kinds = self.reader.iter(events).map(|event| event.kind).collect();
+            (kinds, self.world.get_resource::<Frame>().unwrap().finished)
+        }
+
+        fn playback(&self) -> &Playback {
+            &self.world.get_resource::<Frame>().unwrap().playback
+        }
+    }
+
+    #[test]
+    fn loop_times() {
+        let mut harness = Harness::new(EasingType::Loop {
+            duration: Duration::from_secs(1),
+            pause: None,
+            times: Some(3),
+        });
+        for iteration in 1..3 {
+            assert_eq!(
+                harness.advance(1000),
+                (vec![EasingEventKind::LoopRestarted], false)
+            );
+            assert_eq!(harness.playback().iteration(), iteration);
+        }
+        assert_eq!(harness.advance(1000), (vec![], true));
+    }
+
+    #[test]
+    fn loop_times_with_pause() {
+        let mut harness = Harness::new(EasingType::Loop {
+            duration: Duration::from_secs(1),
+            pause: Some(Duration::from_millis(500)),
+            times: Some(2),
+        });
+        assert_eq!(
+            harness.advance(1000),
+            (vec![EasingEventKind::PauseStarted], false)
+        );
+        assert_eq!(
+            harness.advance(500),
+            (
+                vec![EasingEventKind::PauseEnded, EasingEventKind::LoopRestarted],
+                false
+            )
+        );
+        assert_eq!(harness.playback().iteration(), 1);
+        assert_eq!(harness.advance(1000), (vec![], true));
+    }
+
+    #[test]
+    fn ping_pong_half_trips() {
+        let mut harness = Harness::new(EasingType::PingPong {
+            duration: Duration::from_secs(1),
+            pause: None,
+            times: Some(PingPongTimes::HalfTrips(3)),
+        });
+        for direction in [EasingDirection::Backward, EasingDirection::Forward].iter() {
+            assert_eq!(
+                harness.advance(1000),
+                (vec![EasingEventKind::DirectionReversed], false)
+            );
+            assert_eq!(harness.playback().direction(), *direction);
+        }
+        assert_eq!(harness.advance(1000), (vec![], true));
+    }
+
+    #[test]
+    fn ping_pong_round_trips() {
+        let mut harness = Harness::new(EasingType::PingPong {
+            duration: Duration::from_secs(1),
+            pause: None,
+            times: Some(PingPongTimes::RoundTrips(1)),
+        });
+        assert_eq!(
+            harness.advance(1000),
+            (vec![EasingEventKind::DirectionReversed], false)
+        );
+        assert_eq!(harness.advance(1000), (vec![], true));
+        assert_eq!(harness.playback().iteration(), 2);
+    }
+
+    #[test]
+    fn ping_pong_round_trips_with_pause() {
+        let mut harness = Harness::new(EasingType::PingPong {
+            duration: Duration::from_secs(1),
+            pause: Some(Duration::from_millis(500)),
+            times: Some(PingPongTimes::RoundTrips(1)),
+        });
+        assert_eq!(
+            harness.advance(1000),
+            (
+                vec![
+                    EasingEventKind::DirectionReversed,
+                    EasingEventKind::PauseStarted
+                ],
+                false
+            )
+        );
+        assert_eq!(
+            harness.advance(500),
+            (vec![EasingEventKind::PauseEnded], false)
+        );
+        assert_eq!(harness.playback().direction(), EasingDirection::Backward);
+        assert_eq!(harness.advance(1000), (vec![], true));
+    }
+
+    #[test]
+    fn timeline_before_delay() {
+        let mut playback = Playback::new(
+            Duration::from_secs(1),
+            EasingType::Once {
+                duration: Duration::from_secs(1),
+            },
+        );
+        playback.set_delay(Duration::from_secs(1));
+        assert_eq!(playback.follow_timeline(None), Some(0.));
+        assert!(playback.is_pending());
+        assert_eq!(
+            playback.follow_timeline(Some(Duration::from_millis(250))),
+            Some(0.)
+        );
+        assert!(playback.is_pending());
+        assert_eq!(
+            playback.follow_timeline(Some(Duration::from_millis(1500))),
+            Some(0.5)
+        );
+        assert!(!playback.is_pending());
+    }
+
+    #[test]
+    fn timeline_in_pause() {
+        let mut playback = Playback::new(
+            Duration::from_secs(1),
+            EasingType::Loop {
+                duration: Duration::from_secs(1),
+                pause: Some(Duration::from_millis(500)),
+                times: None,
+            },
+        );
+        assert_eq!(
+            playback.follow_timeline(Some(Duration::from_millis(1250))),
+            Some(1.)
+        );
+        assert_eq!(playback.iteration(), 0);
+        assert_eq!(
+            playback.follow_timeline(Some(Duration::from_millis(1750))),
+            Some(0.25)
+        );
+        assert_eq!(playback.iteration(), 1);
+    }
+
+    #[test]
+    fn timeline_past_end() {
+        let mut playback = Playback::new(
+            Duration::from_secs(1),
+            EasingType::Loop {
+                duration: Duration::from_secs(1),
+                pause: None,
+                times: Some(2),
+            },
+        );
+        assert_eq!(
+            playback.follow_timeline(Some(Duration::from_secs(10))),
+            Some(1.)
+        );
+        assert_eq!(playback.iteration(), 1);
+
+        let mut playback = Playback::new(
+            Duration::from_secs(1),
+            EasingType::PingPong {
+                duration: Duration::from_secs(1),
+                pause: Some(Duration::from_millis(500)),
+                times: Some(PingPongTimes::HalfTrips(2)),
+            },
+        );
+        assert_eq!(
+            playback.follow_timeline(Some(Duration::from_secs(10))),
+            Some(0.)
+        );
+        assert_eq!(playback.iteration(), 1);
+        assert_eq!(playback.direction(), EasingDirection::Backward);
+    }
+}
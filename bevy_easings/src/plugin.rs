@@ -1,13 +1,16 @@
 use bevy::reflect::TypeUuid;
 use bevy::{ecs::component::Component, prelude::*};
 
-use crate::MyEaser;
+use crate::{ApplyEased, LerpWith, MyEaser};
 
 #[cfg(feature = "ease_handle")]
 use crate::IntermediateLerp;
 use crate::{
-    CustomComponentEase, Ease, EaseValue, EasingChainComponent, EasingComponent, EasingState,
-    EasingType,
+    Additive, AdditiveEasing, ChainRepeat, CounterEasing, CustomComponentEase, Ease, EaseField,
+    EaseValue, EasingChainComponent, EasingClock, EasingClocks, EasingComponent, EasingDirection,
+    EasingEvent, EasingEventKind, EasingOptions, EasingTimeSource, FieldEasing, GameClock,
+    KeyframeEasing, LensEasing, SpriteSize, Timeline, TransformAxisAngle, TransformRotation,
+    TransformScale, TransformTranslation, TypewriterEasing, TypewriterEvent, ADDITIVE_EASING_STAGE,
 };
 
 #[derive(Default)]
@@ -19,11 +22,67 @@ pub struct EasingsPlugin;
 
 impl Plugin for EasingsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_system(ease_system::<Sprite>.system())
+        app.add_event::<EasingEvent>()
+            .add_event::<TypewriterEvent>()
+            .init_resource::<GameClock>()
+            .init_resource::<EasingClocks>()
+            .add_system_to_stage(
+                CoreStage::PreUpdate,
+                clock_system.system().label("easing_clocks"),
+            )
+            .add_system_to_stage(
+                CoreStage::PreUpdate,
+                timeline_system.system().after("easing_clocks"),
+            )
+            .add_system_to_stage(CoreStage::PreUpdate, parent_size_system.system())
+            .add_system(ease_system::<Sprite>.system())
             .add_system(ease_system::<ColorMaterial>.system())
             .add_system(ease_system::<Color>.system())
             .add_system(ease_system::<Transform>.system())
-            .add_system(ease_system::<Style>.system());
+            .add_system(ease_system::<Style>.system())
+            .add_system(ease_system::<Text>.system())
+            .add_system(keyframe_ease_system::<Sprite>.system())
+            .add_system(keyframe_ease_system::<ColorMaterial>.system())
+            .add_system(keyframe_ease_system::<Color>.system())
+            .add_system(keyframe_ease_system::<Transform>.system())
+            .add_system(keyframe_ease_system::<Style>.system())
+            .add_system(keyframe_ease_system::<Text>.system())
+            .add_system(typewriter_system.system())
+            .add_system(counter_system.system())
+            .add_system(builtin_field_ease_system::<TransformTranslation>.system())
+            .add_system(builtin_field_ease_system::<TransformRotation>.system())
+            .add_system(builtin_field_ease_system::<TransformScale>.system())
+            .add_system(builtin_field_ease_system::<TransformAxisAngle>.system())
+            .add_system(builtin_field_ease_system::<SpriteSize>.system())
+            .add_stage_after(
+                CoreStage::Update,
+                ADDITIVE_EASING_STAGE,
+                SystemStage::parallel(),
+            )
+            .add_system_to_stage(
+                CoreStage::PreUpdate,
+                additive_reset_system::<Sprite>.system(),
+            )
+            .add_system_to_stage(
+                CoreStage::PreUpdate,
+                additive_reset_system::<Color>.system(),
+            )
+            .add_system_to_stage(
+                CoreStage::PreUpdate,
+                additive_reset_system::<Transform>.system(),
+            )
+            .add_system_to_stage(
+                ADDITIVE_EASING_STAGE,
+                additive_ease_system::<Sprite>.system(),
+            )
+            .add_system_to_stage(
+                ADDITIVE_EASING_STAGE,
+                additive_ease_system::<Color>.system(),
+            )
+            .add_system_to_stage(
+                ADDITIVE_EASING_STAGE,
+                additive_ease_system::<Transform>.system(),
+            );
 
         #[cfg(feature = "ease_handle")]
         app.init_resource::<HandleCache<ColorMaterial>>()
@@ -31,90 +90,245 @@ impl Plugin for EasingsPlugin {
     }
 }
 
+fn clock_system(time: Res<Time>, game: Res<GameClock>, mut clocks: ResMut<EasingClocks>) {
+    clocks.set_delta(EasingClock::Real, time.delta());
+    clocks.set_delta(
+        EasingClock::Game,
+        if game.paused {
+            Default::default()
+        } else {
+            time.delta().mul_f32(game.scale.max(0.))
+        },
+    );
+}
+
+fn timeline_system(mut clocks: ResMut<EasingClocks>, mut query: Query<(Entity, &mut Timeline)>) {
+    clocks.clear_timelines();
+    for (entity, mut timeline) in query.iter_mut() {
+        let delta = clocks.delta(timeline.clock);
+        timeline.advance(delta);
+        clocks.set_timeline(entity, timeline.elapsed());
+    }
+}
+
+/// Record the size and direction of the parent node of `Style` easings, to resolve values in
+/// different units
+fn parent_size_system(
+    windows: Option<Res<Windows>>,
+    nodes: Query<(&Node, &Style)>,
+    mut easing_query: Query<(Option<&Parent>, &mut EasingComponent<Style>)>,
+    mut chain_query: Query<(Option<&Parent>, &mut EasingChainComponent<Style>)>,
+    mut keyframe_query: Query<(Option<&Parent>, &mut KeyframeEasing<Style>)>,
+) {
+    // apps without windows, like headless ones, only use the size of parent nodes
+    let window_size = windows
+        .as_ref()
+        .and_then(|windows| windows.get_primary())
+        .map(|window| Vec2::new(window.width(), window.height()));
+    let parent_node = |parent: Option<&Parent>| {
+        let node = parent.and_then(|parent| nodes.get(parent.0).ok());
+        (
+            node.map(|(node, _)| node.size).or(window_size),
+            node.map(|(_, style)| style.flex_direction),
+        )
+    };
+    for (parent, mut easing) in easing_query.iter_mut() {
+        let (size, direction) = parent_node(parent);
+        easing.parent_size = size;
+        easing.parent_direction = direction;
+    }
+    for (parent, mut easing_chain) in chain_query.iter_mut() {
+        let (size, direction) = parent_node(parent);
+        for easing in easing_chain.easings.iter_mut() {
+            easing.parent_size = size;
+            easing.parent_direction = direction;
+        }
+    }
+    for (parent, mut easing) in keyframe_query.iter_mut() {
+        let (size, direction) = parent_node(parent);
+        easing.parent_size = size;
+        easing.parent_direction = direction;
+    }
+}
+
+/// Clock system for custom time sources. Add this system to your application in
+/// `CoreStage::PreUpdate`, before the label `"easing_clocks"`, with your resource as a type
+/// parameter to use it with `EasingClock::custom`
+pub fn custom_clock_system<R: EasingTimeSource>(
+    time: Res<Time>,
+    source: Res<R>,
+    mut clocks: ResMut<EasingClocks>,
+) {
+    clocks.set_delta(EasingClock::custom::<R>(), source.delta(&time));
+}
+
+/// Result of advancing an easing by one frame
+struct EasingStep {
+    /// Factor to apply to the component, if it should be updated this frame
+    factor: Option<f32>,
+    /// Progress of the easing before applying the ease function
+    progress: f32,
+    /// The easing is done and should be removed
+    finished: bool,
+}
+
+impl<T: 'static> EasingComponent<T> {
+    fn event(&self, entity: Entity, kind: EasingEventKind) -> EasingEvent {
+        self.playback.event::<T>(entity, kind)
+    }
+
+    fn step(
+        &mut self,
+        entity: Entity,
+        clocks: &EasingClocks,
+        events: &mut EventWriter<EasingEvent>,
+    ) -> EasingStep {
+        self.step_as::<T>(entity, clocks, events)
+    }
+
+    /// Advance this easing, sending events as an easing of `E`
+    fn step_as<E: 'static>(
+        &mut self,
+        entity: Entity,
+        clocks: &EasingClocks,
+        events: &mut EventWriter<EasingEvent>,
+    ) -> EasingStep {
+        let step = self
+            .playback
+            .step::<E>(self.state, self.speed, entity, clocks, events);
+        EasingStep {
+            factor: step
+                .progress
+                .map(|progress| progress.compute(self.ease_function)),
+            progress: step.progress.unwrap_or_default(),
+            finished: step.finished,
+        }
+    }
+}
+
+impl<T: 'static> EasingChainComponent<T> {
+    fn event(&self, entity: Entity, kind: EasingEventKind) -> EasingEvent {
+        EasingEvent {
+            iteration: self.iteration,
+            direction: EasingDirection::from_sign(self.direction),
+            ..EasingEvent::new::<T>(entity, kind)
+        }
+    }
+
+    /// Easing currently playing, following the state and speed of the chain
+    fn current_easing(&mut self) -> &mut EasingComponent<T> {
+        let easing = &mut self.easings[self.current];
+        easing.state = self.state;
+        easing.speed = self.speed;
+        easing
+    }
+
+    /// Move to the next easing of the chain, or to the previous one when playing backwards.
+    /// Returns `false` once the chain is done
+    fn advance(&mut self, entity: Entity, events: &mut EventWriter<EasingEvent>) -> bool {
+        let reversed = self.speed < 0.;
+        let step = if reversed {
+            -self.direction
+        } else {
+            self.direction
+        };
+        let next = self.current as isize + step as isize;
+        if next >= 0 && (next as usize) < self.easings.len() {
+            self.current = next as usize;
+        } else {
+            self.iteration += 1;
+            match self.repeat {
+                ChainRepeat::Once => return false,
+                ChainRepeat::Loop { times } => {
+                    if times.map_or(false, |times| self.iteration >= times) {
+                        return false;
+                    }
+                    self.current = if reversed { self.easings.len() - 1 } else { 0 };
+                    events.send(self.event(entity, EasingEventKind::LoopRestarted));
+                }
+                ChainRepeat::PingPong { times } => {
+                    if times.map_or(false, |times| self.iteration >= times.half_trips()) {
+                        return false;
+                    }
+                    self.direction *= -1;
+                    events.send(self.event(entity, EasingEventKind::DirectionReversed));
+                }
+            }
+        }
+        let direction = self.direction;
+        let easing = &mut self.easings[self.current];
+        easing.playback.rewind(direction);
+        if reversed {
+            easing.playback.set_progress(1.);
+        }
+        true
+    }
+}
+
 pub fn ease_system<T: Ease + Component>(
     mut commands: Commands,
-    time: Res<Time>,
+    clocks: Res<EasingClocks>,
+    mut events: EventWriter<EasingEvent>,
     mut query: Query<(Entity, &mut T)>,
     mut easing_query: Query<&mut EasingComponent<T>>,
     mut chain_query: Query<&mut EasingChainComponent<T>>,
 ) where
-    EaseValue<T>: interpolation::Lerp<Scalar = f32>,
-    T: Default,
+    EaseValue<T>: LerpWith,
+    T: ApplyEased + Default,
 {
     for (entity, mut object) in query.iter_mut() {
         if let Ok(ref mut easing) = easing_query.get_mut(entity) {
-            if easing.state == EasingState::Play {
-                easing.timer.tick(time.delta());
-            }
-            if easing.paused {
-                if easing.timer.just_finished() {
-                    match easing.easing_type {
-                        EasingType::Once { duration } => {
-                            easing.timer.set_duration(duration);
-                        }
-                        EasingType::Loop { duration, .. } => {
-                            easing.timer.set_duration(duration);
-                        }
-                        EasingType::PingPong { duration, .. } => {
-                            easing.timer.set_duration(duration);
-                        }
-                    }
-                    easing.timer.reset();
-                    easing.paused = false;
-                }
-            } else {
-                if easing.timer.duration().as_secs_f32() != 0. {
-                    let progress = if easing.direction.is_positive() {
-                        easing.timer.percent()
-                    } else {
-                        easing.timer.percent_left()
-                    };
-                    let factor = progress.compute(easing.ease_function);
-                    if let Some(ref start) = easing.start {
-                        *object = interpolation::lerp(start, &easing.end, &factor).0;
-                    } else {
-                        *object =
-                            interpolation::lerp(&EaseValue(T::default()), &easing.end, &factor).0;
-                    }
-                }
-                if easing.timer.finished() {
-                    match easing.easing_type {
-                        EasingType::Once { .. } => {
-                            commands.entity(entity).remove::<EasingComponent<T>>();
-                        }
-                        EasingType::Loop { pause, .. } => {
-                            if let Some(pause) = pause {
-                                easing.timer.set_duration(pause);
-                                easing.paused = true;
-                            }
-                            easing.timer.reset();
-                        }
-                        EasingType::PingPong { pause, .. } => {
-                            if let Some(pause) = pause {
-                                easing.timer.set_duration(pause);
-                                easing.paused = true;
-                            }
-                            easing.timer.reset();
-                            easing.direction *= -1;
-                        }
-                    }
+            let step = easing.step(entity, &clocks, &mut events);
+            if let Some(factor) = step.factor {
+                let options = easing.lerp_options();
+                if let Some(ref start) = easing.start {
+                    object.apply_eased(
+                        start
+                            .lerp_with(&easing.end, &factor, step.progress, &options)
+                            .0,
+                        &options,
+                    );
+                } else {
+                    object.apply_eased(
+                        EaseValue(T::default())
+                            .lerp_with(&easing.end, &factor, step.progress, &options)
+                            .0,
+                        &options,
+                    );
                 }
             }
-        } else if let Ok(ref mut easing_chain) = chain_query.get_mut(entity) {
-            let next = easing_chain.0.pop();
-            if let Some(mut next) = next {
-                if next.start.is_none() {
-                    next.start = Some(EaseValue(std::mem::take(&mut object)));
+            if step.finished {
+                commands.entity(entity).remove::<EasingComponent<T>>();
+                events.send(easing.event(entity, EasingEventKind::Finished));
+            }
+        } else if let Ok(mut easing_chain) = chain_query.get_mut(entity) {
+            let easing_chain = &mut *easing_chain;
+            let easing = easing_chain.current_easing();
+            if easing.start.is_none() {
+                let start = EaseValue(std::mem::take(&mut *object));
+                *object = start
+                    .lerp_with(&easing.end, &0., 0., &easing.lerp_options())
+                    .0;
+                easing.start = Some(start);
+            }
+            let step = easing.step(entity, &clocks, &mut events);
+            if let Some(factor) = step.factor {
+                if let Some(ref start) = easing.start {
+                    let options = easing.lerp_options();
+                    object.apply_eased(
+                        start
+                            .lerp_with(&easing.end, &factor, step.progress, &options)
+                            .0,
+                        &options,
+                    );
                 }
-                if let Some(ref start) = next.start {
-                    *object = interpolation::lerp(start, &next.end, &0.).0;
-                } else {
-                    *object = interpolation::lerp(&EaseValue(T::default()), &next.end, &0.).0;
+            }
+            if step.finished {
+                events.send(easing.event(entity, EasingEventKind::ChainSegmentFinished));
+                if !easing_chain.advance(entity, &mut events) {
+                    commands.entity(entity).remove::<EasingChainComponent<T>>();
+                    events.send(easing_chain.event(entity, EasingEventKind::ChainFinished));
                 }
-
-                commands.entity(entity).insert(next);
-            } else {
-                commands.entity(entity).remove::<EasingChainComponent<T>>();
             }
         }
     }
@@ -123,7 +337,8 @@ pub fn ease_system<T: Ease + Component>(
 /// Ease system for custom component. Add this system to your application with your component as a type parameter.
 pub fn custom_ease_system<T: CustomComponentEase + Component>(
     mut commands: Commands,
-    time: Res<Time>,
+    clocks: Res<EasingClocks>,
+    mut events: EventWriter<EasingEvent>,
     mut query: Query<(Entity, &mut T)>,
     mut easing_query: Query<&mut EasingComponent<T>>,
     mut chain_query: Query<&mut EasingChainComponent<T>>,
@@ -132,79 +347,377 @@ pub fn custom_ease_system<T: CustomComponentEase + Component>(
 {
     for (entity, mut object) in query.iter_mut() {
         if let Ok(ref mut easing) = easing_query.get_mut(entity) {
-            if easing.state == EasingState::Play {
-                easing.timer.tick(time.delta());
-            }
-            if easing.paused {
-                if easing.timer.just_finished() {
-                    match easing.easing_type {
-                        EasingType::Once { duration } => {
-                            easing.timer.set_duration(duration);
-                        }
-                        EasingType::Loop { duration, .. } => {
-                            easing.timer.set_duration(duration);
-                        }
-                        EasingType::PingPong { duration, .. } => {
-                            easing.timer.set_duration(duration);
-                        }
-                    }
-                    easing.timer.reset();
-                    easing.paused = false;
+            let step = easing.step(entity, &clocks, &mut events);
+            if let Some(factor) = step.factor {
+                if let Some(ref start) = easing.start {
+                    *object = interpolation::lerp(&start.0, &easing.end.0, &factor);
+                } else {
+                    *object = interpolation::lerp(&T::default(), &easing.end.0, &factor);
                 }
-            } else {
-                if easing.timer.duration().as_secs_f32() != 0. {
-                    let progress = if easing.direction.is_positive() {
-                        easing.timer.percent()
-                    } else {
-                        easing.timer.percent_left()
-                    };
-                    let factor = progress.compute(easing.ease_function);
-                    if let Some(ref start) = easing.start {
-                        *object = interpolation::lerp(&start.0, &easing.end.0, &factor);
-                    } else {
-                        *object = interpolation::lerp(&T::default(), &easing.end.0, &factor);
-                    }
+            }
+            if step.finished {
+                commands.entity(entity).remove::<EasingComponent<T>>();
+                events.send(easing.event(entity, EasingEventKind::Finished));
+            }
+        } else if let Ok(mut easing_chain) = chain_query.get_mut(entity) {
+            let easing_chain = &mut *easing_chain;
+            let easing = easing_chain.current_easing();
+            if easing.start.is_none() {
+                let start = EaseValue(std::mem::take(&mut *object));
+                *object = interpolation::lerp(&start.0, &easing.end.0, &0.);
+                easing.start = Some(start);
+            }
+            let step = easing.step(entity, &clocks, &mut events);
+            if let Some(factor) = step.factor {
+                if let Some(ref start) = easing.start {
+                    *object = interpolation::lerp(&start.0, &easing.end.0, &factor);
                 }
-                if easing.timer.finished() {
-                    match easing.easing_type {
-                        EasingType::Once { .. } => {
-                            commands.entity(entity).remove::<EasingComponent<T>>();
-                        }
-                        EasingType::Loop { pause, .. } => {
-                            if let Some(pause) = pause {
-                                easing.timer.set_duration(pause);
-                                easing.paused = true;
-                            }
-                            easing.timer.reset();
-                        }
-                        EasingType::PingPong { pause, .. } => {
-                            if let Some(pause) = pause {
-                                easing.timer.set_duration(pause);
-                                easing.paused = true;
-                            }
-                            easing.timer.reset();
-                            easing.direction *= -1;
-                        }
-                    }
+            }
+            if step.finished {
+                events.send(easing.event(entity, EasingEventKind::ChainSegmentFinished));
+                if !easing_chain.advance(entity, &mut events) {
+                    commands.entity(entity).remove::<EasingChainComponent<T>>();
+                    events.send(easing_chain.event(entity, EasingEventKind::ChainFinished));
                 }
             }
-        } else if let Ok(ref mut easing_chain) = chain_query.get_mut(entity) {
-            let next = easing_chain.0.pop();
-            if let Some(mut next) = next {
-                if next.start.is_none() {
-                    next.start = Some(EaseValue(std::mem::take(&mut object)));
+        }
+    }
+}
+
+pub fn keyframe_ease_system<T: Ease + Component>(
+    mut commands: Commands,
+    clocks: Res<EasingClocks>,
+    mut events: EventWriter<EasingEvent>,
+    mut query: Query<(Entity, &mut T, &mut KeyframeEasing<T>)>,
+) where
+    EaseValue<T>: LerpWith,
+    T: ApplyEased,
+{
+    for (entity, mut object, mut easing) in query.iter_mut() {
+        let (state, speed) = (easing.state, easing.speed);
+        let step = easing
+            .playback
+            .step::<T>(state, speed, entity, &clocks, &mut events);
+        if let Some(progress) = step.progress {
+            let (from, to, progress, factor) = easing.sample(progress);
+            let options = easing.lerp_options();
+            object.apply_eased(from.lerp_with(to, &factor, progress, &options).0, &options);
+        }
+        if step.finished {
+            commands.entity(entity).remove::<KeyframeEasing<T>>();
+            events.send(
+                easing
+                    .playback
+                    .event::<T>(entity, EasingEventKind::Finished),
+            );
+        }
+    }
+}
+
+/// Keyframe ease system for custom component. Add this system to your application with your component as a type parameter.
+pub fn custom_keyframe_ease_system<T: CustomComponentEase + Component>(
+    mut commands: Commands,
+    clocks: Res<EasingClocks>,
+    mut events: EventWriter<EasingEvent>,
+    mut query: Query<(Entity, &mut T, &mut KeyframeEasing<T>)>,
+) where
+    T: interpolation::Lerp<Scalar = f32>,
+{
+    for (entity, mut object, mut easing) in query.iter_mut() {
+        let (state, speed) = (easing.state, easing.speed);
+        let step = easing
+            .playback
+            .step::<T>(state, speed, entity, &clocks, &mut events);
+        if let Some(progress) = step.progress {
+            let (from, to, _, factor) = easing.sample(progress);
+            *object = interpolation::lerp(&from.0, &to.0, &factor);
+        }
+        if step.finished {
+            commands.entity(entity).remove::<KeyframeEasing<T>>();
+            events.send(
+                easing
+                    .playback
+                    .event::<T>(entity, EasingEventKind::Finished),
+            );
+        }
+    }
+}
+
+/// Remove the contribution of additive easings, so that other systems see the base value of the component. Add this system to your application in `CoreStage::PreUpdate` with your component as a type parameter, with `custom_additive_ease_system`.
+pub fn additive_reset_system<T: Additive + Component>(
+    mut query: Query<(&mut T, &mut AdditiveEasing<T>)>,
+) {
+    for (mut object, mut easing) in query.iter_mut() {
+        if let Some(applied) = easing.applied.take() {
+            *object = object.remove(&applied);
+        }
+    }
+}
+
+fn additive_ease_system<T: Additive + Component>(
+    mut commands: Commands,
+    clocks: Res<EasingClocks>,
+    mut events: EventWriter<EasingEvent>,
+    mut query: Query<(Entity, &mut T, &mut AdditiveEasing<T>)>,
+) where
+    EaseValue<T>: LerpWith,
+{
+    for (entity, mut object, mut easing) in query.iter_mut() {
+        let easing = &mut *easing;
+        let step = easing
+            .easing
+            .step_as::<AdditiveEasing<T>>(entity, &clocks, &mut events);
+        if let Some(factor) = step.factor {
+            if let Some(ref start) = easing.easing.start {
+                let delta = start
+                    .lerp_with(
+                        &easing.easing.end,
+                        &factor,
+                        step.progress,
+                        &easing.easing.lerp_options(),
+                    )
+                    .0;
+                *object = object.add(&delta);
+                easing.applied = Some(delta);
+            }
+        }
+        if step.finished {
+            // the last delta stays on the component
+            easing.applied = None;
+            commands.entity(entity).remove::<AdditiveEasing<T>>();
+            events.send(
+                easing
+                    .easing
+                    .playback
+                    .event::<AdditiveEasing<T>>(entity, EasingEventKind::Finished),
+            );
+        }
+    }
+}
+
+/// Additive ease system for custom component. Add this system to your application in the stage `ADDITIVE_EASING_STAGE` with your component as a type parameter, with `additive_reset_system`.
+pub fn custom_additive_ease_system<T: Additive + Component>(
+    mut commands: Commands,
+    clocks: Res<EasingClocks>,
+    mut events: EventWriter<EasingEvent>,
+    mut query: Query<(Entity, &mut T, &mut AdditiveEasing<T>)>,
+) where
+    T: interpolation::Lerp<Scalar = f32>,
+{
+    for (entity, mut object, mut easing) in query.iter_mut() {
+        let easing = &mut *easing;
+        let step = easing
+            .easing
+            .step_as::<AdditiveEasing<T>>(entity, &clocks, &mut events);
+        if let Some(factor) = step.factor {
+            if let Some(ref start) = easing.easing.start {
+                let delta = interpolation::lerp(&start.0, &easing.easing.end.0, &factor);
+                *object = object.add(&delta);
+                easing.applied = Some(delta);
+            }
+        }
+        if step.finished {
+            // the last delta stays on the component
+            easing.applied = None;
+            commands.entity(entity).remove::<AdditiveEasing<T>>();
+            events.send(
+                easing
+                    .easing
+                    .playback
+                    .event::<AdditiveEasing<T>>(entity, EasingEventKind::Finished),
+            );
+        }
+    }
+}
+
+/// Ease system for a field of a component. Add this system to your application with your field as a type parameter.
+pub fn field_ease_system<F: EaseField>(
+    commands: Commands,
+    clocks: Res<EasingClocks>,
+    mut events: EventWriter<EasingEvent>,
+    query: Query<(Entity, &mut F::Component, &mut FieldEasing<F>)>,
+) where
+    F::Value: interpolation::Lerp<Scalar = f32>,
+{
+    ease_fields(
+        commands,
+        &clocks,
+        &mut events,
+        query,
+        |start, end, factor, _, _| interpolation::lerp(&start.0, &end.0, &factor),
+    );
+}
+
+/// Ease system for the fields provided by this crate, following the options of the easing
+fn builtin_field_ease_system<F: EaseField>(
+    commands: Commands,
+    clocks: Res<EasingClocks>,
+    mut events: EventWriter<EasingEvent>,
+    query: Query<(Entity, &mut F::Component, &mut FieldEasing<F>)>,
+) where
+    EaseValue<F::Value>: LerpWith,
+{
+    ease_fields(
+        commands,
+        &clocks,
+        &mut events,
+        query,
+        |start, end, factor, progress, options| start.lerp_with(end, &factor, progress, options).0,
+    );
+}
+
+fn ease_fields<F: EaseField>(
+    mut commands: Commands,
+    clocks: &EasingClocks,
+    events: &mut EventWriter<EasingEvent>,
+    mut query: Query<(Entity, &mut F::Component, &mut FieldEasing<F>)>,
+    lerp: impl Fn(&EaseValue<F::Value>, &EaseValue<F::Value>, f32, f32, &EasingOptions) -> F::Value,
+) {
+    for (entity, mut object, mut easing) in query.iter_mut() {
+        let easing = &mut easing.easing;
+        if easing.start.is_none() {
+            easing.start = Some(EaseValue(F::get(&object)));
+        }
+        let step = easing.step_as::<F>(entity, clocks, events);
+        if let Some(factor) = step.factor {
+            if let Some(ref start) = easing.start {
+                F::set(
+                    &mut object,
+                    lerp(
+                        start,
+                        &easing.end,
+                        factor,
+                        step.progress,
+                        &easing.lerp_options(),
+                    ),
+                );
+            }
+        }
+        if step.finished {
+            commands.entity(entity).remove::<FieldEasing<F>>();
+            events.send(
+                easing
+                    .playback
+                    .event::<F>(entity, EasingEventKind::Finished),
+            );
+        }
+    }
+}
+
+/// Ease system for a `Lens`. Add this system to your application with your component and value as type parameters.
+pub fn lens_ease_system<C: Component, V: Send + Sync + 'static>(
+    mut commands: Commands,
+    clocks: Res<EasingClocks>,
+    mut events: EventWriter<EasingEvent>,
+    mut query: Query<(Entity, &mut C, &mut LensEasing<C, V>)>,
+) where
+    V: interpolation::Lerp<Scalar = f32>,
+{
+    for (entity, mut object, mut lens_easing) in query.iter_mut() {
+        let lens = lens_easing.lens();
+        let easing = &mut lens_easing.easing;
+        if easing.start.is_none() {
+            easing.start = Some(EaseValue(lens.get(&object)));
+        }
+        let step = easing.step_as::<LensEasing<C, V>>(entity, &clocks, &mut events);
+        if let Some(factor) = step.factor {
+            if let Some(ref start) = easing.start {
+                lens.set(
+                    &mut object,
+                    interpolation::lerp(&start.0, &easing.end.0, &factor),
+                );
+            }
+        }
+        if step.finished {
+            commands.entity(entity).remove::<LensEasing<C, V>>();
+            events.send(
+                easing
+                    .playback
+                    .event::<LensEasing<C, V>>(entity, EasingEventKind::Finished),
+            );
+        }
+    }
+}
+
+fn counter_system(
+    mut commands: Commands,
+    clocks: Res<EasingClocks>,
+    mut events: EventWriter<EasingEvent>,
+    mut query: Query<(Entity, &mut Text, &mut CounterEasing)>,
+) {
+    for (entity, mut text, mut counter) in query.iter_mut() {
+        let step = counter
+            .easing
+            .step_as::<CounterEasing>(entity, &clocks, &mut events);
+        if let Some(factor) = step.factor {
+            let value = counter.text(factor);
+            // only write the text when it changes, to avoid updating its layout every frame
+            if text
+                .sections
+                .get(counter.section)
+                .map_or(false, |section| section.value != value)
+            {
+                text.sections[counter.section].value = value;
+            }
+        }
+        if step.finished {
+            commands.entity(entity).remove::<CounterEasing>();
+            events.send(
+                counter
+                    .easing
+                    .playback
+                    .event::<CounterEasing>(entity, EasingEventKind::Finished),
+            );
+        }
+    }
+}
+
+fn typewriter_system(
+    mut commands: Commands,
+    clocks: Res<EasingClocks>,
+    mut events: EventWriter<EasingEvent>,
+    mut typewriter_events: EventWriter<TypewriterEvent>,
+    mut query: Query<(Entity, &mut Text, &mut TypewriterEasing)>,
+) {
+    for (entity, mut text, mut typewriter) in query.iter_mut() {
+        let typewriter = &mut *typewriter;
+        if typewriter.text.is_none() {
+            if let Some(section) = text.sections.get(typewriter.section) {
+                typewriter.text = Some(TypewriterEasing::split(section.value.clone()));
+            }
+        }
+        // without a section to read the text from, the easing still runs to its end
+        let step = typewriter
+            .easing
+            .step_as::<TypewriterEasing>(entity, &clocks, &mut events);
+        if let (Some(factor), Some((full, ends))) = (step.factor, &typewriter.text) {
+            let revealed = ((factor * ends.len() as f32).max(0.) as usize).min(ends.len());
+            if typewriter.revealed != Some(revealed) {
+                let end = if revealed == 0 { 0 } else { ends[revealed - 1] };
+                if let Some(section) = text.sections.get_mut(typewriter.section) {
+                    section.value = full[..end].to_string();
                 }
-                if let Some(ref start) = next.start {
-                    *object = interpolation::lerp(&start.0, &next.end.0, &0.);
-                } else {
-                    *object = interpolation::lerp(&T::default(), &next.end.0, &0.);
+                // the first count is where the easing starts, not a change
+                if typewriter.revealed.is_some() {
+                    typewriter_events.send(TypewriterEvent {
+                        entity,
+                        section: typewriter.section,
+                        revealed,
+                        total: ends.len(),
+                    });
                 }
-
-                commands.entity(entity).insert(next);
-            } else {
-                commands.entity(entity).remove::<EasingChainComponent<T>>();
+                typewriter.revealed = Some(revealed);
             }
         }
+        if step.finished {
+            commands.entity(entity).remove::<TypewriterEasing>();
+            events.send(
+                typewriter
+                    .easing
+                    .playback
+                    .event::<TypewriterEasing>(entity, EasingEventKind::Finished),
+            );
+        }
     }
 }
 
@@ -212,7 +725,8 @@ pub fn custom_ease_system<T: CustomComponentEase + Component>(
 #[allow(clippy::too_many_arguments)]
 fn handle_ease_system<T: Ease + Component + TypeUuid>(
     mut commands: Commands,
-    time: Res<Time>,
+    clocks: Res<EasingClocks>,
+    mut events: EventWriter<EasingEvent>,
     mut assets: ResMut<Assets<T>>,
     mut handle_cache: ResMut<HandleCache<T>>,
     mut query: Query<(Entity, &mut Handle<T>)>,
@@ -223,81 +737,64 @@ fn handle_ease_system<T: Ease + Component + TypeUuid>(
 {
     for (entity, mut object) in query.iter_mut() {
         if let Ok(ref mut easing) = easing_query.get_mut(entity) {
-            if easing.state == EasingState::Play {
-                easing.timer.tick(time.delta());
+            let step = easing.step(entity, &clocks, &mut events);
+            if let Some(factor) = step.factor {
+                *object = eased_handle(easing, factor, &mut assets, &mut handle_cache);
             }
-            if easing.paused {
-                if easing.timer.just_finished() {
-                    match easing.easing_type {
-                        EasingType::Once { duration } => {
-                            easing.timer.set_duration(duration);
-                        }
-                        EasingType::Loop { duration, .. } => {
-                            easing.timer.set_duration(duration);
-                        }
-                        EasingType::PingPong { duration, .. } => {
-                            easing.timer.set_duration(duration);
-                        }
-                    }
-                    easing.timer.reset();
-                    easing.paused = false;
-                }
-            } else {
-                if easing.timer.duration().as_secs_f32() != 0. {
-                    let progress = if easing.direction.is_positive() {
-                        easing.timer.percent()
-                    } else {
-                        easing.timer.percent_left()
-                    };
-                    let factor = progress.compute(easing.ease_function);
-                    let factor_simplified = (factor * 25.) as i16;
-                    let handle = handle_cache
-                        .0
-                        .entry(easing.id + (easing.direction * factor_simplified) as i128)
-                        .or_insert_with(|| {
-                            let start = assets.get(&easing.start.as_ref().unwrap().0).unwrap();
-                            let end = assets.get(&easing.end.0).unwrap();
-                            let intermediate =
-                                IntermediateLerp::lerp(&EaseValue(start), &EaseValue(end), &factor);
-
-                            assets.add(intermediate)
-                        })
-                        .clone();
-                    *object = handle;
-                }
-                if easing.timer.finished() {
-                    match easing.easing_type {
-                        EasingType::Once { .. } => {
-                            commands.entity(entity).remove::<EasingComponent<T>>();
-                        }
-                        EasingType::Loop { pause, .. } => {
-                            if let Some(pause) = pause {
-                                easing.timer.set_duration(pause);
-                                easing.paused = true;
-                            }
-                            easing.timer.reset();
-                        }
-                        EasingType::PingPong { pause, .. } => {
-                            if let Some(pause) = pause {
-                                easing.timer.set_duration(pause);
-                                easing.paused = true;
-                            }
-                            easing.timer.reset();
-                            easing.direction *= -1;
-                        }
-                    }
-                }
+            if step.finished {
+                commands
+                    .entity(entity)
+                    .remove::<EasingComponent<Handle<T>>>();
+                events.send(easing.event(entity, EasingEventKind::Finished));
+            }
+        } else if let Ok(mut easing_chain) = chain_query.get_mut(entity) {
+            let easing_chain = &mut *easing_chain;
+            let easing = easing_chain.current_easing();
+            if easing.start.is_none() {
+                easing.start = Some(EaseValue(object.clone()));
+            }
+            let step = easing.step(entity, &clocks, &mut events);
+            if let Some(factor) = step.factor {
+                *object = eased_handle(easing, factor, &mut assets, &mut handle_cache);
             }
-        } else if let Ok(ref mut easing_chain) = chain_query.get_mut(entity) {
-            let next = easing_chain.0.pop();
-            if let Some(mut next) = next {
-                if next.start.is_none() {
-                    next.start = Some(EaseValue(object.clone()));
+            if step.finished {
+                events.send(easing.event(entity, EasingEventKind::ChainSegmentFinished));
+                if !easing_chain.advance(entity, &mut events) {
+                    commands
+                        .entity(entity)
+                        .remove::<EasingChainComponent<Handle<T>>>();
+                    events.send(easing_chain.event(entity, EasingEventKind::ChainFinished));
                 }
-                commands.entity(entity).insert(next);
-            } else {
-                commands.entity(entity).remove::<EasingChainComponent<T>>();
             }
         }
     }
 }
+
+#[cfg(feature = "ease_handle")]
+fn eased_handle<T: Ease + Component + TypeUuid>(
+    easing: &EasingComponent<Handle<T>>,
+    factor: f32,
+    assets: &mut Assets<T>,
+    handle_cache: &mut HandleCache<T>,
+) -> Handle<T>
+where
+    T: IntermediateLerp,
+{
+    let factor_simplified = (factor * 25.) as i16;
+    handle_cache
+        .0
+        .entry(easing.id + (easing.playback.direction * factor_simplified) as i128)
+        .or_insert_with(|| {
+            let start = assets.get(&easing.start.as_ref().unwrap().0).unwrap();
+            let end = assets.get(&easing.end.0).unwrap();
+            let intermediate = IntermediateLerp::lerp(
+                &EaseValue(start),
+                &EaseValue(end),
+                &factor,
+                &easing.lerp_options(),
+            );
+
+            assets.add(intermediate)
+        })
+        .clone()
+}
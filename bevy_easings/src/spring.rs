@@ -0,0 +1,160 @@
+use std::time::Duration;
+
+/// A damped spring, pulling the eased value from its start to its end. The duration of the easing
+/// is the time the spring takes to settle, and the eased value can overshoot its end.
+///
+/// The spring is simulated on a normalized displacement: it starts at `0.`, targets `1.`, and
+/// `initial_velocity` is expressed in that unit per second.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spring {
+    stiffness: f32,
+    damping: f32,
+    mass: f32,
+    initial_velocity: f32,
+    threshold: f32,
+    settle_time: f32,
+}
+
+impl Default for Spring {
+    fn default() -> Self {
+        Spring::new(170., 26., 1.)
+    }
+}
+
+/// Springs that don't settle are stopped after this time, in seconds
+const MAX_SETTLE_TIME: f32 = 60.;
+/// Time step used to find when the spring settles, in seconds
+const SETTLE_TIME_STEP: f32 = 1. / 240.;
+
+impl Spring {
+    /// Create a new spring at rest
+    pub fn new(stiffness: f32, damping: f32, mass: f32) -> Self {
+        Spring {
+            stiffness: stiffness.max(f32::EPSILON),
+            damping: damping.max(0.),
+            mass: mass.max(f32::EPSILON),
+            initial_velocity: 0.,
+            threshold: 0.001,
+            settle_time: 0.,
+        }
+        .settled()
+    }
+
+    /// Set the velocity of the spring when the easing starts
+    pub fn with_initial_velocity(mut self, initial_velocity: f32) -> Self {
+        self.initial_velocity = initial_velocity;
+        self.settled()
+    }
+
+    /// Set under which displacement and velocity the spring is considered at rest. Defaults to
+    /// `0.001`
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold.max(f32::EPSILON);
+        self.settled()
+    }
+
+    /// Time the spring takes to come to rest
+    pub fn settle_duration(&self) -> Duration {
+        Duration::from_secs_f32(self.settle_time)
+    }
+
+    /// Position of the spring after `progress` of its settle duration. `0.` at start, `1.` at rest
+    pub(crate) fn position(&self, progress: f32) -> f32 {
+        if progress >= 1. {
+            1.
+        } else {
+            1. + self
+                .displacement_and_velocity(progress * self.settle_time)
+                .0
+        }
+    }
+
+    fn settled(mut self) -> Self {
+        let mut time = 0.;
+        while time < MAX_SETTLE_TIME {
+            let (displacement, velocity) = self.displacement_and_velocity(time);
+            if displacement.abs() < self.threshold && velocity.abs() < self.threshold {
+                break;
+            }
+            time += SETTLE_TIME_STEP;
+        }
+        self.settle_time = time.min(MAX_SETTLE_TIME);
+        self
+    }
+
+    /// Displacement from the target and velocity of the spring after `time` seconds
+    fn displacement_and_velocity(&self, time: f32) -> (f32, f32) {
+        let omega = (self.stiffness / self.mass).sqrt();
+        let zeta = self.damping / (2. * (self.stiffness * self.mass).sqrt());
+        let initial = -1.;
+        let velocity = self.initial_velocity;
+
+        if zeta < 1. {
+            let decay = zeta * omega;
+            let omega_d = omega * (1. - zeta * zeta).sqrt();
+            let a = initial;
+            let b = (velocity + decay * a) / omega_d;
+            let envelope = (-decay * time).exp();
+            let (sin, cos) = (omega_d * time).sin_cos();
+            (
+                envelope * (a * cos + b * sin),
+                envelope * ((b * omega_d - decay * a) * cos - (a * omega_d + decay * b) * sin),
+            )
+        } else if (zeta - 1.).abs() < 1e-3 {
+            let a = initial;
+            let b = velocity + omega * a;
+            let envelope = (-omega * time).exp();
+            (
+                envelope * (a + b * time),
+                envelope * (b - omega * (a + b * time)),
+            )
+        } else {
+            let root = (zeta * zeta - 1.).sqrt();
+            let r1 = -omega * (zeta - root);
+            let r2 = -omega * (zeta + root);
+            let c2 = (velocity - r1 * initial) / (r2 - r1);
+            let c1 = initial - c2;
+            let (e1, e2) = ((r1 * time).exp(), (r2 * time).exp());
+            (c1 * e1 + c2 * e2, c1 * r1 * e1 + c2 * r2 * e2)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn settles_in_finite_time() {
+        for spring in [
+            Spring::default(),
+            Spring::new(100., 5., 1.),
+            Spring::new(100., 40., 1.),
+        ]
+        .iter()
+        {
+            assert!(spring.settle_duration() > Duration::default());
+            assert!(spring.settle_duration().as_secs_f32() < MAX_SETTLE_TIME);
+        }
+        assert_eq!(
+            Spring::new(100., 0., 1.).settle_duration().as_secs_f32(),
+            MAX_SETTLE_TIME
+        );
+    }
+
+    #[test]
+    fn ends_at_target() {
+        assert_eq!(Spring::default().position(0.), 0.);
+        assert_eq!(Spring::default().position(1.), 1.);
+        assert_eq!(Spring::new(100., 5., 1.).position(1.), 1.);
+    }
+
+    #[test]
+    fn underdamped_overshoots() {
+        let spring = Spring::new(100., 5., 1.);
+        let highest = (0..=100)
+            .map(|step| spring.position(step as f32 / 100.))
+            .fold(f32::MIN, f32::max);
+        assert!(highest > 1.);
+    }
+}
@@ -0,0 +1,57 @@
+use std::time::Duration;
+
+use crate::{EasingClock, EasingState};
+
+/// Component driving a group of easings on one clock. Easings are added to a timeline with
+/// `in_timeline`, and playing, pausing, seeking or reversing the timeline applies to all of them
+#[derive(Debug, Clone, Copy)]
+pub struct Timeline {
+    /// Control if this timeline is played or not
+    pub state: EasingState,
+    /// Speed at which this timeline is played, `1.` by default. A negative speed plays the
+    /// timeline backwards
+    pub speed: f32,
+    elapsed: Duration,
+    pub(crate) clock: Option<EasingClock>,
+}
+
+impl Default for Timeline {
+    fn default() -> Self {
+        Timeline {
+            state: EasingState::Play,
+            speed: 1.,
+            elapsed: Duration::default(),
+            clock: None,
+        }
+    }
+}
+
+impl Timeline {
+    /// Advance this timeline with `clock` instead of the default one
+    pub fn with_clock(mut self, clock: EasingClock) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    /// Time elapsed since the start of this timeline
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Jump to `elapsed` since the start of this timeline. Its easings will be updated on the next
+    /// run of the easing systems
+    pub fn seek(&mut self, elapsed: Duration) {
+        self.elapsed = elapsed;
+    }
+
+    pub(crate) fn advance(&mut self, delta: Duration) {
+        if self.state == EasingState::Play {
+            let delta = delta.mul_f32(self.speed.abs());
+            self.elapsed = if self.speed < 0. {
+                self.elapsed.checked_sub(delta).unwrap_or_default()
+            } else {
+                self.elapsed + delta
+            };
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{EaseMethod, EasingComponent, EasingType};
+
+/// Component revealing the value of a section of a `Text` one character at a time. Characters are
+/// grapheme clusters, so that accents and emojis are revealed at once
+pub struct TypewriterEasing {
+    pub(crate) section: usize,
+    /// Full value of the section, and the byte offset of the end of each character
+    pub(crate) text: Option<(String, Vec<usize>)>,
+    pub(crate) revealed: Option<usize>,
+    /// Easing of the ratio of characters revealed, from `0.` to `1.`
+    pub easing: EasingComponent<f32>,
+}
+
+impl std::fmt::Debug for TypewriterEasing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TypewriterEasing")
+            .field("section", &self.section)
+            .field("revealed", &self.revealed)
+            .field("easing", &self.easing)
+            .finish()
+    }
+}
+
+impl TypewriterEasing {
+    /// Reveal the current value of the section `section` of the `Text`
+    pub fn new(
+        section: usize,
+        ease_function: impl Into<EaseMethod>,
+        easing_type: EasingType,
+    ) -> Self {
+        TypewriterEasing {
+            section,
+            text: None,
+            revealed: None,
+            easing: EasingComponent::new(Some(0.), 1., ease_function, easing_type),
+        }
+    }
+
+    /// Reveal `text` in the section `section` of the `Text`, replacing its current value
+    pub fn with_text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(Self::split(text.into()));
+        self
+    }
+
+    pub(crate) fn split(text: String) -> (String, Vec<usize>) {
+        let ends = text
+            .grapheme_indices(true)
+            .map(|(index, grapheme)| index + grapheme.len())
+            .collect();
+        (text, ends)
+    }
+}
+
+/// Event sent when a `TypewriterEasing` reveals or hides characters. It is not sent for the
+/// characters visible when the easing starts
+#[derive(Debug, Clone, Copy)]
+pub struct TypewriterEvent {
+    /// Entity with the `Text`
+    pub entity: Entity,
+    /// Section of the `Text` being revealed
+    pub section: usize,
+    /// Number of characters now visible
+    pub revealed: usize,
+    /// Number of characters in the section
+    pub total: usize,
+}